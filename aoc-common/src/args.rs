@@ -0,0 +1,24 @@
+use std::{error::Error, fmt::Display};
+
+// an option of a day binary that is missing its value or cannot use the one given
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArgumentError {
+    MissingValue(String),
+    InvalidValue(String, String),
+    // an option for a command other than the one given
+    Unexpected(String),
+}
+
+impl Display for ArgumentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingValue(option) => write!(f, "No value given for {}", option),
+            Self::InvalidValue(option, value) => {
+                write!(f, "Invalid value for {}: {}", option, value)
+            }
+            Self::Unexpected(option) => write!(f, "Unexpected option: {}", option),
+        }
+    }
+}
+
+impl Error for ArgumentError {}
@@ -0,0 +1,231 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, Div, Mul, Rem},
+};
+
+// an unsigned integer of any size, as 64 bit limbs with the least significant first
+// and no leading zero limbs, so zero has none
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    limbs: Vec<u64>,
+}
+
+impl BigUint {
+    pub fn zero() -> BigUint {
+        BigUint { limbs: vec![] }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    pub fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(top) => self.limbs.len() as u64 * 64 - u64::from(top.leading_zeros()),
+            None => 0,
+        }
+    }
+
+    // the value if it fits a u64
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.limbs[..] {
+            [] => Some(0),
+            [value] => Some(value),
+            _ => None,
+        }
+    }
+
+    fn normalized(mut self) -> BigUint {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+        self
+    }
+
+    pub fn div_rem(&self, divisor: u64) -> (BigUint, u64) {
+        assert!(divisor != 0, "division by zero");
+        let mut quotient = vec![0; self.limbs.len()];
+        let mut remainder = 0u128;
+        for (i, &limb) in self.limbs.iter().enumerate().rev() {
+            let value = (remainder << 64) | u128::from(limb);
+            quotient[i] = (value / u128::from(divisor)) as u64;
+            remainder = value % u128::from(divisor);
+        }
+        (BigUint { limbs: quotient }.normalized(), remainder as u64)
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        BigUint { limbs: vec![value] }.normalized()
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add<u64> for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, rhs: u64) -> BigUint {
+        let mut limbs = self.limbs.clone();
+        let mut carry = rhs;
+        for limb in limbs.iter_mut() {
+            if carry == 0 {
+                break;
+            }
+            let (sum, overflow) = limb.overflowing_add(carry);
+            *limb = sum;
+            carry = u64::from(overflow);
+        }
+        if carry > 0 {
+            limbs.push(carry);
+        }
+        BigUint { limbs }
+    }
+}
+
+impl Add<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, rhs: &BigUint) -> BigUint {
+        let (long, short) = if self.limbs.len() >= rhs.limbs.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let mut limbs = Vec::with_capacity(long.limbs.len() + 1);
+        let mut carry = 0u128;
+        for (i, &limb) in long.limbs.iter().enumerate() {
+            let sum = u128::from(limb) + u128::from(*short.limbs.get(i).unwrap_or(&0)) + carry;
+            limbs.push(sum as u64);
+            carry = sum >> 64;
+        }
+        limbs.push(carry as u64);
+        BigUint { limbs }.normalized()
+    }
+}
+
+impl Mul<u64> for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, rhs: u64) -> BigUint {
+        let mut limbs = Vec::with_capacity(self.limbs.len() + 1);
+        let mut carry = 0u128;
+        for &limb in &self.limbs {
+            let product = u128::from(limb) * u128::from(rhs) + carry;
+            limbs.push(product as u64);
+            carry = product >> 64;
+        }
+        limbs.push(carry as u64);
+        BigUint { limbs }.normalized()
+    }
+}
+
+// long multiplication, which is plenty for numbers of a few thousand digits
+impl Mul<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, rhs: &BigUint) -> BigUint {
+        let mut limbs = vec![0u64; self.limbs.len() + rhs.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, &b) in rhs.limbs.iter().enumerate() {
+                let product = u128::from(a) * u128::from(b) + u128::from(limbs[i + j]) + carry;
+                limbs[i + j] = product as u64;
+                carry = product >> 64;
+            }
+            limbs[i + rhs.limbs.len()] = carry as u64;
+        }
+        BigUint { limbs }.normalized()
+    }
+}
+
+impl Div<u64> for &BigUint {
+    type Output = BigUint;
+
+    fn div(self, rhs: u64) -> BigUint {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem<u64> for &BigUint {
+    type Output = u64;
+
+    fn rem(self, rhs: u64) -> u64 {
+        self.div_rem(rhs).1
+    }
+}
+
+impl Display for BigUint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // nineteen decimal digits at a time, the most that fit a limb
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+        let mut chunks = vec![];
+        let mut rest = self.clone();
+        while !rest.is_zero() {
+            let (quotient, chunk) = rest.div_rem(CHUNK);
+            chunks.push(chunk);
+            rest = quotient;
+        }
+        let mut digits = match chunks.pop() {
+            Some(top) => top.to_string(),
+            None => "0".to_string(),
+        };
+        for chunk in chunks.iter().rev() {
+            digits.push_str(&format!("{:019}", chunk));
+        }
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let max = BigUint::from(u64::MAX);
+        let sum = &max + 1;
+        assert_eq!(65, sum.bits());
+        assert_eq!(None, sum.to_u64());
+        assert_eq!("18446744073709551616", sum.to_string());
+
+        let square = &sum * &sum;
+        assert_eq!(
+            "340282366920938463463374607431768211456",
+            square.to_string()
+        );
+        assert_eq!(square, &(&sum * u64::MAX) + &sum);
+        assert_eq!(sum, &(&square / (1 << 32)) / (1 << 32));
+        assert_eq!(1, &square % 3);
+        assert_eq!(Some(0), (&BigUint::from(7) / 8).to_u64());
+    }
+
+    #[test]
+    fn ordering_and_display() {
+        let small = BigUint::from(19);
+        let large = &BigUint::from(1) * &(&BigUint::from(u64::MAX) + 1);
+        assert!(small < large);
+        assert!(BigUint::zero() < small);
+        assert_eq!("0", BigUint::zero().to_string());
+        assert_eq!("  19", format!("{:>4}", small));
+        assert_eq!(
+            "10000000000000000000000",
+            (&BigUint::from(10_u64.pow(11)) * 10_u64.pow(11)).to_string()
+        );
+    }
+}
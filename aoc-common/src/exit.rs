@@ -0,0 +1,15 @@
+use std::{fmt::Display, process};
+
+// for the binaries: report the error the same way for every day and stop
+pub trait OrExit<T> {
+    fn or_exit(self) -> T;
+}
+
+impl<T, E: Display> OrExit<T> for Result<T, E> {
+    fn or_exit(self) -> T {
+        self.unwrap_or_else(|err| {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        })
+    }
+}
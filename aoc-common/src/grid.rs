@@ -0,0 +1,198 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{
+    parse::{lines, ParseError},
+    Point2,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn from_fn<F: FnMut(Point2) -> T>(width: usize, height: usize, mut f: F) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point2::new(x as i32, y as i32)))
+            .map(&mut f)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // one cell per character, every line of the input has to be the same length;
+    // characters the closure doesn't recognise are reported as parse errors
+    pub fn parse<F>(input: &str, mut f: F) -> Result<Grid<T>, ParseError>
+    where
+        F: FnMut(Point2, char) -> Option<T>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for (y, line) in lines(input).enumerate() {
+            let row_start = cells.len();
+            for (x, ch) in line.text.chars().enumerate() {
+                match f(Point2::new(x as i32, y as i32), ch) {
+                    Some(cell) => cells.push(cell),
+                    None => {
+                        return Err(line.error(format!("unexpected {:?} at column {}", ch, x + 1)))
+                    }
+                }
+            }
+            let row_width = cells.len() - row_start;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(
+                        line.error(format!("row has {} cells, expected {}", row_width, width))
+                    )
+                }
+                _ => (),
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point2) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+
+    fn offset(&self, p: Point2) -> Option<usize> {
+        self.contains(p)
+            .then(|| p.y as usize * self.width + p.x as usize)
+    }
+
+    pub fn get(&self, p: Point2) -> Option<&T> {
+        self.offset(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point2) -> Option<&mut T> {
+        self.offset(p).map(|i| &mut self.cells[i])
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Point2> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point2::new(x as i32, y as i32)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point2, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn find<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> Option<Point2> {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(p, _)| p)
+    }
+
+    pub fn neighbours4(&self, p: Point2) -> impl Iterator<Item = Point2> + '_ {
+        p.neighbours4().filter(|n| self.contains(*n))
+    }
+
+    pub fn neighbours8(&self, p: Point2) -> impl Iterator<Item = Point2> + '_ {
+        p.neighbours8().filter(|n| self.contains(*n))
+    }
+}
+
+impl<T> Index<Point2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point2) -> &T {
+        match self.get(p) {
+            Some(value) => value,
+            None => panic!("{:?} is outside of the grid", p),
+        }
+    }
+}
+
+impl<T> IndexMut<Point2> for Grid<T> {
+    fn index_mut(&mut self, p: Point2) -> &mut T {
+        match self.offset(p) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("{:?} is outside of the grid", p),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#..
+.#.
+..#
+##.
+";
+
+    #[test]
+    fn parse_and_index() {
+        let grid = Grid::parse(EXAMPLE, |_, ch| Some(ch == '#')).unwrap();
+        assert_eq!(3, grid.width());
+        assert_eq!(4, grid.height());
+        assert!(grid[Point2::new(0, 0)]);
+        assert!(!grid[Point2::new(1, 0)]);
+        assert!(grid[Point2::new(1, 3)]);
+        assert_eq!(None, grid.get(Point2::new(3, 0)));
+        assert_eq!(None, grid.get(Point2::new(0, -1)));
+        assert_eq!(5, grid.iter().filter(|(_, wall)| **wall).count());
+        assert_eq!(Some(Point2::new(1, 0)), grid.find(|wall| !wall));
+    }
+
+    #[test]
+    fn rows_and_neighbours() {
+        let mut grid = Grid::from_fn(3, 2, |p| p.x + p.y * 3);
+        grid[Point2::new(2, 1)] = -1;
+        let rows = grid.rows().collect::<Vec<_>>();
+        assert_eq!(vec![&[0, 1, 2][..], &[3, 4, -1][..]], rows);
+        assert_eq!(2, grid.neighbours4(Point2::ORIGIN).count());
+        assert_eq!(5, grid.neighbours8(Point2::new(1, 0)).count());
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Grid::parse("..\n.\n", |_, ch| Some(ch)).unwrap_err();
+        assert_eq!(
+            "line 2: row has 1 cells, expected 2 in \".\"",
+            err.to_string()
+        );
+        let err = Grid::parse("#.\n#x\n", |_, ch| (ch != 'x').then_some(ch)).unwrap_err();
+        assert_eq!(
+            "line 2: unexpected 'x' at column 2 in \"#x\"",
+            err.to_string()
+        );
+    }
+}
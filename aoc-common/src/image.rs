@@ -0,0 +1,384 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::render::{Image, RenderError, Rgb};
+
+pub fn encode_ppm(image: &Image) -> Vec<u8> {
+    let mut data = format!("P6\n{} {}\n255\n", image.width(), image.height()).into_bytes();
+    data.extend(image.pixels().iter().flatten());
+    data
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (a, b) = bytes.iter().fold((1u32, 0u32), |(a, b), &byte| {
+        let a = (a + byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+    (b << 16) | a
+}
+
+fn png_chunk(data: &mut Vec<u8>, kind: &[u8; 4], content: &[u8]) {
+    data.extend((content.len() as u32).to_be_bytes());
+    let start = data.len();
+    data.extend(kind);
+    data.extend(content);
+    let crc = crc32(&data[start..]);
+    data.extend(crc.to_be_bytes());
+}
+
+// a zlib stream of stored deflate blocks: no compression, which keeps the
+// encoder small at the cost of file size
+fn zlib_stored(raw: &[u8]) -> Vec<u8> {
+    let mut data = vec![0x78, 0x01];
+    let blocks = raw.chunks(0xffff).collect::<Vec<_>>();
+    for (i, block) in blocks.iter().enumerate() {
+        data.push(u8::from(i == blocks.len() - 1));
+        let len = block.len() as u16;
+        data.extend(len.to_le_bytes());
+        data.extend((!len).to_le_bytes());
+        data.extend(*block);
+    }
+    if blocks.is_empty() {
+        data.extend([0x01, 0x00, 0x00, 0xff, 0xff]);
+    }
+    data.extend(adler32(raw).to_be_bytes());
+    data
+}
+
+pub fn encode_png(image: &Image) -> Vec<u8> {
+    let mut data = b"\x89PNG\r\n\x1a\n".to_vec();
+
+    let mut header = vec![];
+    header.extend((image.width() as u32).to_be_bytes());
+    header.extend((image.height() as u32).to_be_bytes());
+    // 8 bits per channel, truecolour, default compression, filter and interlacing
+    header.extend([8, 2, 0, 0, 0]);
+    png_chunk(&mut data, b"IHDR", &header);
+
+    let mut raw = Vec::with_capacity((image.width() * 3 + 1) * image.height());
+    for row in image.pixels().chunks(image.width().max(1)) {
+        raw.push(0);
+        raw.extend(row.iter().flatten());
+    }
+    png_chunk(&mut data, b"IDAT", &zlib_stored(&raw));
+    png_chunk(&mut data, b"IEND", &[]);
+    data
+}
+
+pub const MAX_GIF_COLOURS: usize = 256;
+
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bits: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, size: u32) {
+        self.buffer |= (code as u32) << self.bits;
+        self.bits += size;
+        while self.bits >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+const MAX_CODE: u16 = 4096;
+
+// variable length LZW as GIF uses it, starting over with a clear code once the
+// table of 4096 codes is full
+fn lzw(indices: &[u8], min_code_size: u32) -> Vec<u8> {
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+    let mut writer = BitWriter {
+        bytes: vec![],
+        buffer: 0,
+        bits: 0,
+    };
+    let mut code_size = min_code_size + 1;
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next = end + 1;
+
+    writer.write(clear, code_size);
+    let mut indices = indices.iter();
+    if let Some(&first) = indices.next() {
+        let mut prefix = first as u16;
+        for &index in indices {
+            if let Some(&code) = table.get(&(prefix, index)) {
+                prefix = code;
+                continue;
+            }
+            writer.write(prefix, code_size);
+            if next < MAX_CODE {
+                table.insert((prefix, index), next);
+                if next == 1 << code_size {
+                    code_size += 1;
+                }
+                next += 1;
+            } else {
+                writer.write(clear, code_size);
+                table.clear();
+                next = end + 1;
+                code_size = min_code_size + 1;
+            }
+            prefix = index as u16;
+        }
+        writer.write(prefix, code_size);
+    }
+    writer.write(end, code_size);
+    writer.finish()
+}
+
+fn sub_blocks(data: &mut Vec<u8>, bytes: &[u8]) {
+    for block in bytes.chunks(255) {
+        data.push(block.len() as u8);
+        data.extend(block);
+    }
+    data.push(0);
+}
+
+// an animation looping forever, `delay` is the time between frames in hundredths
+// of a second; all frames share one palette and must be the same size
+pub fn encode_gif(frames: &[Image], delay: u16) -> Result<Vec<u8>, usize> {
+    let (width, height) = frames
+        .first()
+        .map_or((0, 0), |frame| (frame.width(), frame.height()));
+
+    let mut colours = frames
+        .iter()
+        .flat_map(|frame| frame.pixels())
+        .copied()
+        .collect::<HashSet<Rgb>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+    if colours.len() > MAX_GIF_COLOURS {
+        return Err(colours.len());
+    }
+    colours.sort_unstable();
+    let palette = colours
+        .iter()
+        .enumerate()
+        .map(|(i, colour)| (*colour, i as u8))
+        .collect::<HashMap<_, _>>();
+    let bits = (1..=8).find(|bits| colours.len() <= 1 << bits).unwrap();
+
+    let mut data = b"GIF89a".to_vec();
+    data.extend((width as u16).to_le_bytes());
+    data.extend((height as u16).to_le_bytes());
+    data.extend([0x80 | 0x70 | (bits as u8 - 1), 0, 0]);
+    for i in 0..1 << bits {
+        data.extend(colours.get(i).unwrap_or(&[0, 0, 0]));
+    }
+    // the NETSCAPE2.0 extension, repeating the animation forever
+    data.extend(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+    for frame in frames {
+        data.extend([0x21, 0xf9, 0x04, 0x00]);
+        data.extend(delay.to_le_bytes());
+        data.extend([0x00, 0x00]);
+
+        data.push(0x2c);
+        data.extend([0, 0, 0, 0]);
+        data.extend((frame.width() as u16).to_le_bytes());
+        data.extend((frame.height() as u16).to_le_bytes());
+        data.push(0);
+
+        let min_code_size = bits.max(2);
+        data.push(min_code_size as u8);
+        let indices = frame
+            .pixels()
+            .iter()
+            .map(|colour| palette[colour])
+            .collect::<Vec<_>>();
+        sub_blocks(&mut data, &lzw(&indices, min_code_size));
+    }
+
+    data.push(0x3b);
+    Ok(data)
+}
+
+// hundredths of a second between the frames of an animation
+pub const FRAME_DELAY: u16 = 5;
+// images are scaled up until their longer side reaches this many pixels
+const TARGET_SIZE: usize = 480;
+const MAX_SCALE: usize = 8;
+const BACKGROUND: Rgb = [0, 0, 0];
+
+fn scale_for(width: usize, height: usize) -> usize {
+    (TARGET_SIZE / width.max(height).max(1)).clamp(1, MAX_SCALE)
+}
+
+pub fn save(
+    dir: &Path,
+    name: &str,
+    frames: &[Image],
+    last: &Image,
+) -> Result<Vec<PathBuf>, RenderError> {
+    let write = |extension: &str, data: Vec<u8>| {
+        let path = dir.join(format!("{}.{}", name, extension));
+        fs::write(&path, data).map_err(|err| RenderError::Io(path.clone(), err))?;
+        Ok(path)
+    };
+
+    let scale = scale_for(last.width(), last.height());
+    let last = last.scaled(scale);
+    let mut written = vec![
+        write("ppm", encode_ppm(&last))?,
+        write("png", encode_png(&last))?,
+    ];
+
+    if frames.len() > 1 {
+        // the grids of some days grow, the animation is as large as the largest frame
+        let width = frames.iter().map(Image::width).max().unwrap();
+        let height = frames.iter().map(Image::height).max().unwrap();
+        let scale = scale_for(width, height);
+        let frames = frames
+            .iter()
+            .map(|frame| frame.padded(width, height, BACKGROUND).scaled(scale))
+            .collect::<Vec<_>>();
+        let gif = encode_gif(&frames, FRAME_DELAY).map_err(RenderError::TooManyColours)?;
+        written.push(write("gif", gif)?);
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the reverse of `lzw`, to check the codes it writes
+    fn unlzw(data: &[u8], min_code_size: u32) -> Vec<u8> {
+        let clear = 1u16 << min_code_size;
+        let end = clear + 1;
+        let mut bits = data
+            .iter()
+            .flat_map(|byte| (0..8).map(move |bit| (byte >> bit) & 1));
+        let mut read = |size: u32| -> u16 {
+            (0..size).fold(0, |code, bit| code | ((bits.next().unwrap() as u16) << bit))
+        };
+
+        let mut output = vec![];
+        let mut table: Vec<Vec<u8>> = vec![];
+        let mut code_size = min_code_size + 1;
+        let mut previous: Option<Vec<u8>> = None;
+        loop {
+            let code = read(code_size);
+            if code == clear {
+                table = (0..clear).map(|i| vec![i as u8]).collect();
+                table.extend([vec![], vec![]]);
+                code_size = min_code_size + 1;
+                previous = None;
+                continue;
+            }
+            if code == end {
+                return output;
+            }
+            let entry = match table.get(code as usize) {
+                Some(entry) => entry.clone(),
+                None => {
+                    let mut entry = previous.clone().unwrap();
+                    entry.push(entry[0]);
+                    entry
+                }
+            };
+            if let Some(mut previous) = previous {
+                if table.len() < MAX_CODE as usize {
+                    previous.push(entry[0]);
+                    table.push(previous);
+                    if table.len() == 1 << code_size && code_size < 12 {
+                        code_size += 1;
+                    }
+                }
+            }
+            output.extend(&entry);
+            previous = Some(entry);
+        }
+    }
+
+    #[test]
+    fn lzw_round_trip() {
+        let repetitive = (0..20_000).map(|i| (i / 7 % 3) as u8).collect::<Vec<_>>();
+        assert_eq!(repetitive, unlzw(&lzw(&repetitive, 2), 2));
+
+        // enough different sequences to fill the table and start over
+        let mut state = 12345u32;
+        let noisy = (0..50_000)
+            .map(|_| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                (state >> 16) as u8
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(noisy, unlzw(&lzw(&noisy, 8), 8));
+
+        assert_eq!(Vec::<u8>::new(), unlzw(&lzw(&[], 2), 2));
+    }
+
+    #[test]
+    fn checksums() {
+        assert_eq!(0xcbf4_3926, crc32(b"123456789"));
+        assert_eq!(0x091e_01de, adler32(b"123456789"));
+    }
+
+    fn checkerboard() -> Image {
+        Image::from_fn(3, 2, |x, y| {
+            if (x + y) % 2 == 0 {
+                [255, 255, 255]
+            } else {
+                [0, 0, 0]
+            }
+        })
+    }
+
+    #[test]
+    fn ppm() {
+        let data = encode_ppm(&checkerboard());
+        assert!(data.starts_with(b"P6\n3 2\n255\n"));
+        assert_eq!(b"P6\n3 2\n255\n".len() + 3 * 2 * 3, data.len());
+    }
+
+    #[test]
+    fn png() {
+        let data = encode_png(&checkerboard());
+        assert!(data.starts_with(b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR"));
+        assert!(data.ends_with(b"IEND\xae\x42\x60\x82"));
+    }
+
+    #[test]
+    fn gif() {
+        let frames = [checkerboard(), checkerboard()];
+        let data = encode_gif(&frames, 10).unwrap();
+        assert!(data.starts_with(b"GIF89a\x03\x00\x02\x00"));
+        assert_eq!(Some(&0x3b), data.last());
+
+        let rainbow = Image::from_fn(300, 1, |x, _| [x as u8, (x / 256) as u8, 0]);
+        assert_eq!(Err(300), encode_gif(&[rainbow], 10));
+    }
+}
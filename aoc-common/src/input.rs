@@ -0,0 +1,117 @@
+use std::{
+    borrow::Cow,
+    env,
+    error::Error,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::OrExit;
+
+#[derive(Debug)]
+pub enum InputError {
+    File(PathBuf, io::Error),
+    Stdin(io::Error),
+    UnexpectedArgument(String),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File(path, err) => write!(f, "Cannot read {}: {}", path.display(), err),
+            Self::Stdin(err) => write!(f, "Cannot read standard input: {}", err),
+            Self::UnexpectedArgument(arg) => write!(f, "Unexpected argument: {}", arg),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::File(_, err) | Self::Stdin(err) => Some(err),
+            Self::UnexpectedArgument(_) => None,
+        }
+    }
+}
+
+pub fn read_file<P: AsRef<Path>>(path: P) -> Result<String, InputError> {
+    let path = path.as_ref();
+    fs::read_to_string(path).map_err(|err| InputError::File(path.to_path_buf(), err))
+}
+
+pub fn read_stdin() -> Result<String, InputError> {
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .map_err(InputError::Stdin)?;
+    Ok(input)
+}
+
+pub fn read(arg: &str) -> Result<String, InputError> {
+    match arg {
+        "-" => read_stdin(),
+        path => read_file(path),
+    }
+}
+
+pub fn from_args<I>(mut args: I, bundled: &'static str) -> Result<Cow<'static, str>, InputError>
+where
+    I: Iterator<Item = String>,
+{
+    let arg = args.next();
+    if let Some(extra) = args.next() {
+        return Err(InputError::UnexpectedArgument(extra));
+    }
+    match arg {
+        Some(arg) => Ok(Cow::Owned(read(&arg)?)),
+        None => Ok(Cow::Borrowed(bundled)),
+    }
+}
+
+pub fn load(bundled: &'static str) -> Cow<'static, str> {
+    from_args(env::args().skip(1), bundled).or_exit()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        args.iter()
+            .map(|arg| arg.to_string())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn bundled_input_without_arguments() {
+        let input = from_args(args(&[]), "bundled").unwrap();
+        assert_eq!("bundled", input);
+    }
+
+    #[test]
+    fn input_from_file() {
+        let path = env::temp_dir().join("aoc-common-input-from-file.txt");
+        fs::write(&path, "1\n2\n3\n").unwrap();
+        let input = from_args(args(&[path.to_str().unwrap()]), "bundled").unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!("1\n2\n3\n", input);
+    }
+
+    #[test]
+    fn missing_file() {
+        let err = from_args(args(&["no/such/input.txt"]), "bundled").unwrap_err();
+        assert!(matches!(err, InputError::File(ref path, _) if path.ends_with("input.txt")));
+        assert!(err
+            .to_string()
+            .starts_with("Cannot read no/such/input.txt: "));
+    }
+
+    #[test]
+    fn too_many_arguments() {
+        let err = from_args(args(&["-", "extra"]), "bundled");
+        assert!(matches!(err, Err(InputError::UnexpectedArgument(arg)) if arg == "extra"));
+    }
+}
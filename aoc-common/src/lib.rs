@@ -0,0 +1,24 @@
+pub mod args;
+pub mod bigint;
+pub mod direction;
+pub mod exit;
+pub mod grid;
+#[cfg(feature = "render")]
+pub mod image;
+pub mod input;
+pub mod ocr;
+pub mod parallel;
+pub mod parse;
+pub mod point;
+pub mod render;
+pub mod solution;
+pub mod visualize;
+
+pub use args::ArgumentError;
+pub use bigint::BigUint;
+pub use direction::Direction;
+pub use exit::OrExit;
+pub use grid::Grid;
+pub use parse::ParseError;
+pub use point::{Point2, Point3};
+pub use solution::{run, run_with, take_options, NoAnswer, Solution};
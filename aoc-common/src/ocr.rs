@@ -0,0 +1,142 @@
+use std::{error::Error, fmt::Display};
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+
+// letters are drawn side by side with an empty column between them
+const GLYPH_SPACING: usize = GLYPH_WIDTH + 1;
+
+// the letters the puzzles are known to draw, lit pixels as '#'
+const FONT: [(char, [&str; GLYPH_HEIGHT]); 17] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    Height(usize),
+    UnknownGlyph { index: usize, glyph: String },
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Height(rows) => write!(
+                f,
+                "Letters are {} rows high, the screen has {}",
+                GLYPH_HEIGHT, rows
+            ),
+            Self::UnknownGlyph { index, glyph } => {
+                write!(f, "Unrecognised letter {}:\n{}", index + 1, glyph)
+            }
+        }
+    }
+}
+
+impl Error for OcrError {}
+
+// the glyph at `column` as rows of '#' and '.', whatever the screen used for
+// unlit pixels
+fn glyph<S: AsRef<str>>(rows: &[S], column: usize) -> [String; GLYPH_HEIGHT] {
+    std::array::from_fn(|y| {
+        let row = rows[y].as_ref().chars().skip(column);
+        row.chain(std::iter::repeat(' '))
+            .take(GLYPH_WIDTH)
+            .map(|ch| if ch == '#' { '#' } else { '.' })
+            .collect()
+    })
+}
+
+fn recognise(glyph: &[String; GLYPH_HEIGHT]) -> Option<char> {
+    FONT.iter()
+        .find(|(_, pixels)| pixels.iter().zip(glyph).all(|(a, b)| a == b))
+        .map(|(letter, _)| *letter)
+}
+
+// reads the letters drawn with '#' across six rows of a screen; blank space after
+// the last letter is ignored
+pub fn read<S: AsRef<str>>(rows: &[S]) -> Result<String, OcrError> {
+    if rows.len() != GLYPH_HEIGHT {
+        return Err(OcrError::Height(rows.len()));
+    }
+    let width = rows
+        .iter()
+        .map(|row| {
+            row.as_ref()
+                .trim_end_matches(|ch| ch != '#')
+                .chars()
+                .count()
+        })
+        .max()
+        .unwrap_or(0);
+    (0..width)
+        .step_by(GLYPH_SPACING)
+        .enumerate()
+        .map(|(index, column)| {
+            let glyph = glyph(rows, column);
+            recognise(&glyph).ok_or_else(|| OcrError::UnknownGlyph {
+                index,
+                glyph: glyph.join("\n"),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn letters() {
+        let screen = [
+            "#  #  ##  ###  #### ",
+            "#  # #  # #  # #    ",
+            "#### #  # #  # ###  ",
+            "#  # #  # ###  #    ",
+            "#  # #  # # #  #    ",
+            "#  #  ##  #  # #    ",
+        ];
+        assert_eq!(Ok("HORF".to_string()), read(&screen));
+        assert_eq!(Ok("H".to_string()), read(&screen.map(|row| &row[..4])));
+        assert_eq!(Ok(String::new()), read(&[""; 6]));
+    }
+
+    #[test]
+    fn font_is_unambiguous() {
+        for (i, (_, a)) in FONT.iter().enumerate() {
+            for (_, b) in &FONT[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn unknown_letters() {
+        let screen = [
+            "#  # ##", "#  # ##", "####   ", "#  #   ", "#  #   ", "#  #   ",
+        ];
+        assert_eq!(
+            Err(OcrError::UnknownGlyph {
+                index: 1,
+                glyph: "##..\n##..\n....\n....\n....\n....".to_string()
+            }),
+            read(&screen)
+        );
+        assert_eq!(Err(OcrError::Height(2)), read(&screen[..2]));
+    }
+}
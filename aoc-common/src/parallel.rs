@@ -0,0 +1,235 @@
+use std::{
+    env,
+    num::NonZeroUsize,
+    panic,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        OnceLock,
+    },
+    thread,
+};
+
+use crate::ArgumentError;
+
+// the number of threads when --threads is not given
+pub const THREADS_VARIABLE: &str = "AOC_THREADS";
+
+static THREADS: OnceLock<NonZeroUsize> = OnceLock::new();
+
+// for --threads, which wins over AOC_THREADS as long as nothing has run in
+// parallel yet
+pub fn set_threads(threads: NonZeroUsize) {
+    let _ = THREADS.set(threads);
+}
+
+// from --threads or AOC_THREADS, or as many as the machine runs at once
+pub fn threads() -> NonZeroUsize {
+    *THREADS.get_or_init(|| {
+        if let Ok(value) = env::var(THREADS_VARIABLE) {
+            match value.trim().parse() {
+                Ok(threads) => return threads,
+                Err(_) => eprintln!(
+                    "warning: ignoring {}={:?}, which is not a number of threads",
+                    THREADS_VARIABLE, value
+                ),
+            }
+        }
+        thread::available_parallelism().unwrap_or(NonZeroUsize::MIN)
+    })
+}
+
+// takes --threads <N> out of a day binary's arguments
+pub fn from_args<I>(mut args: I) -> Result<(Option<NonZeroUsize>, Vec<String>), ArgumentError>
+where
+    I: Iterator<Item = String>,
+{
+    let mut threads = None;
+    let mut rest = vec![];
+    while let Some(arg) = args.next() {
+        if arg != "--threads" {
+            rest.push(arg);
+            continue;
+        }
+        let value = args
+            .next()
+            .ok_or_else(|| ArgumentError::MissingValue(arg.clone()))?;
+        threads = Some(
+            value
+                .parse()
+                .map_err(|_| ArgumentError::InvalidValue(arg, value))?,
+        );
+    }
+    Ok((threads, rest))
+}
+
+// scoped worker threads that each take the next piece of work as soon as they are
+// done with the last, so a few slow items do not hold up the rest
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pool {
+    threads: NonZeroUsize,
+}
+
+impl Pool {
+    pub fn new(threads: NonZeroUsize) -> Pool {
+        Pool { threads }
+    }
+
+    // with the number of threads set by --threads or AOC_THREADS
+    pub fn configured() -> Pool {
+        Pool::new(threads())
+    }
+
+    pub fn threads(&self) -> usize {
+        self.threads.get()
+    }
+
+    // `f` of every index below `count`, in order
+    pub fn map_indices<R, F>(&self, count: usize, f: F) -> Vec<R>
+    where
+        R: Send,
+        F: Fn(usize) -> R + Sync,
+    {
+        let workers = self.threads().min(count);
+        if workers <= 1 {
+            return (0..count).map(f).collect();
+        }
+        let next = AtomicUsize::new(0);
+        let mut results = thread::scope(|s| {
+            let handles = (0..workers)
+                .map(|_| {
+                    s.spawn(|| {
+                        let mut done = vec![];
+                        loop {
+                            let i = next.fetch_add(1, Ordering::Relaxed);
+                            if i >= count {
+                                return done;
+                            }
+                            done.push((i, f(i)));
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .flat_map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|err| panic::resume_unwind(err))
+                })
+                .collect::<Vec<_>>()
+        });
+        results.sort_unstable_by_key(|(i, _)| *i);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+
+    pub fn map<T, R, F>(&self, items: &[T], f: F) -> Vec<R>
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&T) -> R + Sync,
+    {
+        self.map_indices(items.len(), |i| f(&items[i]))
+    }
+
+    // the value for the first item that has one, as a search in order would find;
+    // items after one that has a value are not started
+    pub fn find_map<T, R, F>(&self, items: &[T], f: F) -> Option<R>
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&T) -> Option<R> + Sync,
+    {
+        let found = AtomicUsize::new(usize::MAX);
+        let results = self.map_indices(items.len(), |i| {
+            if i > found.load(Ordering::Relaxed) {
+                return None;
+            }
+            let result = f(&items[i]);
+            if result.is_some() {
+                found.fetch_min(i, Ordering::Relaxed);
+            }
+            result
+        });
+        results.into_iter().flatten().next()
+    }
+}
+
+pub fn map_indices<R: Send, F: Fn(usize) -> R + Sync>(count: usize, f: F) -> Vec<R> {
+    Pool::configured().map_indices(count, f)
+}
+
+pub fn map<T: Sync, R: Send, F: Fn(&T) -> R + Sync>(items: &[T], f: F) -> Vec<R> {
+    Pool::configured().map(items, f)
+}
+
+pub fn find_map<T: Sync, R: Send, F: Fn(&T) -> Option<R> + Sync>(items: &[T], f: F) -> Option<R> {
+    Pool::configured().find_map(items, f)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Mutex, thread::ThreadId, time::Duration};
+
+    use super::*;
+
+    fn pool(threads: usize) -> Pool {
+        Pool::new(NonZeroUsize::new(threads).unwrap())
+    }
+
+    #[test]
+    fn results_in_order() {
+        let items = (0..100).collect::<Vec<u64>>();
+        let expected = items.iter().map(|n| n * n).collect::<Vec<_>>();
+        for threads in [1, 3, 8, 200] {
+            assert_eq!(expected, pool(threads).map(&items, |n| n * n));
+        }
+        assert_eq!(Vec::<u64>::new(), pool(4).map(&[], |n: &u64| *n));
+    }
+
+    #[test]
+    fn work_is_shared() {
+        let workers = Mutex::new(Vec::<ThreadId>::new());
+        pool(4).map_indices(40, |_| {
+            workers.lock().unwrap().push(thread::current().id());
+            thread::sleep(Duration::from_millis(1));
+        });
+        let mut workers = workers.into_inner().unwrap();
+        assert_eq!(40, workers.len());
+        workers.sort_unstable_by_key(|id| format!("{:?}", id));
+        workers.dedup();
+        assert!(workers.len() > 1 && workers.len() <= 4);
+        assert!(!workers.contains(&thread::current().id()));
+    }
+
+    #[test]
+    fn first_found() {
+        let items = (0..1000).collect::<Vec<u32>>();
+        for threads in [1, 2, 7] {
+            let pool = pool(threads);
+            assert_eq!(
+                Some(49),
+                pool.find_map(&items, |&n| (n > 0 && n % 49 == 0).then_some(n))
+            );
+            assert_eq!(None, pool.find_map(&items, |&n| (n > 1000).then_some(n)));
+        }
+    }
+
+    #[test]
+    fn thread_option() {
+        let args = ["--threads", "3", "input.txt"]
+            .map(String::from)
+            .into_iter();
+        assert_eq!(
+            Ok((NonZeroUsize::new(3), vec!["input.txt".to_string()])),
+            from_args(args)
+        );
+        let args = ["--threads", "0"].map(String::from).into_iter();
+        assert_eq!(
+            Err(ArgumentError::InvalidValue(
+                "--threads".to_string(),
+                "0".to_string()
+            )),
+            from_args(args)
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    text: String,
+    reason: String,
+}
+
+impl ParseError {
+    pub fn new<R: Display>(line: usize, text: &str, reason: R) -> ParseError {
+        ParseError {
+            line,
+            text: text.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    // for sections that are missing altogether, reported on the line after the last one
+    pub fn end_of_input<R: Display>(input: &str, reason: R) -> ParseError {
+        ParseError::new(input.lines().count() + 1, "", reason)
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.text.is_empty() {
+            write!(f, "line {}: {}", self.line, self.reason)
+        } else {
+            write!(f, "line {}: {} in {:?}", self.line, self.reason, self.text)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn error<R: Display>(&self, reason: R) -> ParseError {
+        ParseError::new(self.number, self.text, reason)
+    }
+
+    pub fn parse<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.text.parse().map_err(|err| self.error(err))
+    }
+}
+
+// lines numbered from one, the way an editor shows them
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    numbered_lines(input, 1)
+}
+
+fn numbered_lines(input: &str, first: usize) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(move |(i, text)| Line {
+        number: first + i,
+        text,
+    })
+}
+
+// run a parser over every line, tagging its failures with the line they came from
+pub fn parse_lines<'a, T, E, F>(
+    input: &'a str,
+    mut f: F,
+) -> impl Iterator<Item = Result<T, ParseError>> + 'a
+where
+    E: Display,
+    F: FnMut(&'a str) -> Result<T, E> + 'a,
+{
+    lines(input).map(move |line| f(line.text).map_err(|err| line.error(err)))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block<'a> {
+    pub first_line: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Block<'a> {
+    pub fn lines(&self) -> impl Iterator<Item = Line<'a>> {
+        numbered_lines(self.text, self.first_line)
+    }
+
+    pub fn error<R: Display>(&self, reason: R) -> ParseError {
+        ParseError::new(
+            self.first_line,
+            self.text.lines().next().unwrap_or_default(),
+            reason,
+        )
+    }
+}
+
+// sections of the input separated by blank lines
+pub fn blocks(input: &str) -> impl Iterator<Item = Block<'_>> {
+    let mut first_line = 1;
+    input.split("\n\n").map(move |text| {
+        let block = Block { first_line, text };
+        first_line += text.lines().count() + 1;
+        block
+    })
+}
+
+// every (optionally negative) whole number in the text, ignoring the words in between
+pub fn integers<T: FromStr>(s: &str) -> impl Iterator<Item = T> + '_ {
+    s.split(|ch: char| !ch.is_ascii_digit() && ch != '-')
+        .filter(|part| !part.is_empty())
+        .filter_map(|part| part.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_in_text() {
+        let line = "Sensor at x=2, y=-18: closest beacon is at x=-2, y=15";
+        assert_eq!(
+            vec![2, -18, -2, 15],
+            integers::<i32>(line).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![498, 4, 498, 6],
+            integers::<u32>("498,4 -> 498,6").collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn errors_point_at_the_line() {
+        let result =
+            parse_lines("1\n2\nthree\n4\n", str::parse::<i32>).collect::<Result<Vec<_>, _>>();
+        let err = result.unwrap_err();
+        assert_eq!(3, err.line());
+        assert_eq!("three", err.text());
+        assert_eq!(
+            "line 3: invalid digit found in string in \"three\"",
+            err.to_string()
+        );
+        assert_eq!(
+            "line 6: missing moves",
+            ParseError::end_of_input("a\nb\n\nc\nd\n", "missing moves").to_string()
+        );
+    }
+
+    #[test]
+    fn blocks_keep_line_numbers() {
+        let input = "a\nb\n\nc\n\nd\ne\n";
+        let blocks = blocks(input).collect::<Vec<_>>();
+        assert_eq!(
+            vec![1, 4, 6],
+            blocks.iter().map(|b| b.first_line).collect::<Vec<_>>()
+        );
+        let last = blocks[2].lines().last().unwrap();
+        assert_eq!(
+            Line {
+                number: 7,
+                text: "e"
+            },
+            last
+        );
+        let err = blocks[1].lines().next().unwrap().parse::<u8>().unwrap_err();
+        assert_eq!((4, "c"), (err.line(), err.text()));
+    }
+}
@@ -0,0 +1,237 @@
+use std::{
+    num::ParseIntError,
+    ops::{Add, AddAssign, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::Direction;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+const OFFSETS_8: [(i32, i32); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2::new(0, 0);
+
+    pub const fn new(x: i32, y: i32) -> Point2 {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan_distance(self, other: Point2) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn step(self, direction: Direction) -> Point2 {
+        self + direction.offset()
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Point2> {
+        Direction::ALL.into_iter().map(move |dir| self.step(dir))
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Point2> {
+        OFFSETS_8
+            .into_iter()
+            .map(move |(x, y)| Point2::new(self.x + x, self.y + y))
+    }
+
+    // top left and bottom right corners of the smallest box holding all of the points
+    pub fn bounds<I: IntoIterator<Item = Point2>>(points: I) -> Option<(Point2, Point2)> {
+        let mut iter = points.into_iter();
+        let first = iter.next()?;
+        Some(iter.fold((first, first), |(min, max), p| {
+            (
+                Point2::new(min.x.min(p.x), min.y.min(p.y)),
+                Point2::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        }))
+    }
+}
+
+impl Add for Point2 {
+    type Output = Point2;
+
+    fn add(self, rhs: Point2) -> Point2 {
+        Point2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point2 {
+    fn add_assign(&mut self, rhs: Point2) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point2 {
+    type Output = Point2;
+
+    fn sub(self, rhs: Point2) -> Point2 {
+        Point2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point2 {
+    fn sub_assign(&mut self, rhs: Point2) {
+        *self = *self - rhs;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsePointError {
+    WrongNumberOfCoordinates(usize),
+    InvalidCoordinate(ParseIntError),
+}
+
+impl std::fmt::Display for ParsePointError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::WrongNumberOfCoordinates(n) => write!(f, "Wrong number of coordinates: {}", n),
+            Self::InvalidCoordinate(err) => write!(f, "Invalid coordinate: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ParsePointError {}
+
+fn parse_coordinates<const N: usize>(s: &str) -> Result<[i32; N], ParsePointError> {
+    let parts = s.split(',').map(str::trim).collect::<Vec<_>>();
+    if parts.len() != N {
+        return Err(ParsePointError::WrongNumberOfCoordinates(parts.len()));
+    }
+    let mut result = [0; N];
+    for (value, part) in result.iter_mut().zip(parts) {
+        *value = part.parse().map_err(ParsePointError::InvalidCoordinate)?;
+    }
+    Ok(result)
+}
+
+impl FromStr for Point2 {
+    type Err = ParsePointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y] = parse_coordinates(s)?;
+        Ok(Point2::new(x, y))
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Point3 {
+    pub const fn new(x: i32, y: i32, z: i32) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    pub fn neighbours6(self) -> impl Iterator<Item = Point3> {
+        [
+            (-1, 0, 0),
+            (1, 0, 0),
+            (0, -1, 0),
+            (0, 1, 0),
+            (0, 0, -1),
+            (0, 0, 1),
+        ]
+        .into_iter()
+        .map(move |(x, y, z)| Point3::new(self.x + x, self.y + y, self.z + z))
+    }
+
+    pub fn bounds<I: IntoIterator<Item = Point3>>(points: I) -> Option<(Point3, Point3)> {
+        let mut iter = points.into_iter();
+        let first = iter.next()?;
+        Some(iter.fold((first, first), |(min, max), p| {
+            (
+                Point3::new(min.x.min(p.x), min.y.min(p.y), min.z.min(p.z)),
+                Point3::new(max.x.max(p.x), max.y.max(p.y), max.z.max(p.z)),
+            )
+        }))
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, rhs: Point3) -> Point3 {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, rhs: Point3) -> Point3 {
+        Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl FromStr for Point3 {
+    type Err = ParsePointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] = parse_coordinates(s)?;
+        Ok(Point3::new(x, y, z))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbours() {
+        let p = Point2::new(3, 7);
+        let n4 = p.neighbours4().collect::<Vec<_>>();
+        assert_eq!(4, n4.len());
+        assert!(n4.iter().all(|n| n.manhattan_distance(p) == 1));
+        let n8 = p.neighbours8().collect::<Vec<_>>();
+        assert_eq!(8, n8.len());
+        assert!(n4.iter().all(|n| n8.contains(n)));
+        assert!(!n8.contains(&p));
+
+        let p = Point3::new(1, 2, 3);
+        let n6 = p.neighbours6().collect::<Vec<_>>();
+        assert_eq!(6, n6.len());
+        assert!(n6.contains(&Point3::new(1, 2, 4)));
+        assert!(n6.contains(&Point3::new(0, 2, 3)));
+    }
+
+    #[test]
+    fn bounds() {
+        let points = [Point2::new(2, -1), Point2::new(-3, 4), Point2::new(0, 0)];
+        assert_eq!(
+            Some((Point2::new(-3, -1), Point2::new(2, 4))),
+            Point2::bounds(points)
+        );
+        assert_eq!(None, Point2::bounds([]));
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(Ok(Point2::new(498, 4)), "498,4".parse());
+        assert_eq!(Ok(Point3::new(2, -2, 5)), "2, -2, 5".parse());
+        assert_eq!(
+            Err(ParsePointError::WrongNumberOfCoordinates(3)),
+            "1,2,3".parse::<Point2>()
+        );
+        assert!(matches!(
+            "1,x".parse::<Point2>(),
+            Err(ParsePointError::InvalidCoordinate(_))
+        ));
+    }
+}
@@ -0,0 +1,305 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+};
+
+use crate::Point2;
+
+pub type Rgb = [u8; 3];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn from_fn<F: FnMut(usize, usize) -> Rgb>(width: usize, height: usize, mut f: F) -> Image {
+        let pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    // one pixel per character of a frame drawn for the terminal, rows padded with
+    // the colour of a space
+    pub fn from_text(text: &str, colour: impl Fn(char) -> Rgb) -> Image {
+        let rows = text.lines().collect::<Vec<_>>();
+        let width = rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0);
+        let cells = rows
+            .iter()
+            .map(|row| row.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        Image::from_fn(width, rows.len(), |x, y| {
+            colour(cells[y].get(x).copied().unwrap_or(' '))
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixels(&self) -> &[Rgb] {
+        &self.pixels
+    }
+
+    pub fn scaled(&self, factor: usize) -> Image {
+        Image::from_fn(self.width * factor, self.height * factor, |x, y| {
+            self.pixels[y / factor * self.width + x / factor]
+        })
+    }
+
+    // the image in the middle of a larger one
+    pub fn padded(&self, width: usize, height: usize, fill: Rgb) -> Image {
+        let left = width.saturating_sub(self.width) / 2;
+        let top = height.saturating_sub(self.height) / 2;
+        Image::from_fn(width, height, |x, y| {
+            match (x.checked_sub(left), y.checked_sub(top)) {
+                (Some(x), Some(y)) if x < self.width && y < self.height => {
+                    self.pixels[y * self.width + x]
+                }
+                _ => fill,
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum RenderError {
+    Unsupported,
+    Io(PathBuf, io::Error),
+    TooManyColours(usize),
+}
+
+impl Display for RenderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsupported => write!(
+                f,
+                "Rendering images needs a build with the `render` feature, e.g. --features render"
+            ),
+            Self::Io(path, err) => write!(f, "Cannot write {}: {}", path.display(), err),
+            Self::TooManyColours(count) => {
+                write!(f, "A GIF can have 256 colours, the frames have {}", count)
+            }
+        }
+    }
+}
+
+impl Error for RenderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(_, err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+// frames beyond this thin out the animation, dropping every other frame
+pub const MAX_FRAMES: usize = 300;
+
+// the frames of one puzzle run, saved when it is finished
+struct Recording {
+    name: String,
+    frames: Vec<Image>,
+    last: Option<Image>,
+    count: usize,
+    step: usize,
+}
+
+impl Recording {
+    fn new(name: &str) -> Recording {
+        Recording {
+            name: name.to_string(),
+            frames: vec![],
+            last: None,
+            count: 0,
+            step: 1,
+        }
+    }
+
+    // whether the next frame makes it into the animation
+    fn wants_frame(&self) -> bool {
+        self.count.is_multiple_of(self.step)
+    }
+
+    fn add(&mut self, frame: Option<Image>) {
+        if let Some(frame) = frame {
+            if self.frames.len() == MAX_FRAMES {
+                self.frames = self.frames.drain(..).step_by(2).collect();
+                self.step *= 2;
+            }
+            if self.wants_frame() {
+                self.frames.push(frame.clone());
+            }
+            self.last = Some(frame);
+        }
+        self.count += 1;
+    }
+}
+
+struct Recorder {
+    dir: PathBuf,
+    recording: Option<Recording>,
+}
+
+static RECORDER: OnceLock<Mutex<Recorder>> = OnceLock::new();
+
+pub fn enable<P: AsRef<Path>>(dir: P) -> Result<(), RenderError> {
+    let dir = dir.as_ref();
+    if !cfg!(feature = "render") {
+        return Err(RenderError::Unsupported);
+    }
+    std::fs::create_dir_all(dir).map_err(|err| RenderError::Io(dir.to_path_buf(), err))?;
+    let _ = RECORDER.set(Mutex::new(Recorder {
+        dir: dir.to_path_buf(),
+        recording: None,
+    }));
+    Ok(())
+}
+
+pub fn enabled() -> bool {
+    RECORDER.get().is_some()
+}
+
+// the frames that follow belong to `name`, which also names the files
+pub fn start(name: &str) {
+    if let Some(recorder) = RECORDER.get() {
+        recorder.lock().unwrap().recording = Some(Recording::new(name));
+    }
+}
+
+// like `visualize::frame`, `render` only runs while frames are being recorded, and
+// only for the frames kept once a long animation thins out
+pub fn frame<R: FnOnce() -> Image>(render: R) {
+    if let Some(recorder) = RECORDER.get() {
+        if let Some(recording) = &mut recorder.lock().unwrap().recording {
+            let frame = recording.wants_frame().then(render);
+            recording.add(frame);
+        }
+    }
+}
+
+// the state a simulation ended in, which becomes the still images even when the
+// animation skipped over it
+pub fn last<R: FnOnce() -> Image>(render: R) {
+    if let Some(recorder) = RECORDER.get() {
+        if let Some(recording) = &mut recorder.lock().unwrap().recording {
+            recording.last = Some(render());
+        }
+    }
+}
+
+// saves the last frame as a PPM and a PNG image and all of them as an animated
+// GIF, returning the files written
+pub fn finish() -> Result<Vec<PathBuf>, RenderError> {
+    let Some(recorder) = RECORDER.get() else {
+        return Ok(vec![]);
+    };
+    let mut recorder = recorder.lock().unwrap();
+    match recorder.recording.take() {
+        Some(Recording {
+            name,
+            frames,
+            last: Some(last),
+            ..
+        }) => save(&recorder.dir, &name, &frames, &last),
+        _ => Ok(vec![]),
+    }
+}
+
+#[cfg(feature = "render")]
+use crate::image::save;
+
+#[cfg(not(feature = "render"))]
+fn save(_: &Path, _: &str, _: &[Image], _: &Image) -> Result<Vec<PathBuf>, RenderError> {
+    Err(RenderError::Unsupported)
+}
+
+// the rows from `min` to `max` inclusive, one pixel per position
+pub fn draw(min: Point2, max: Point2, cell: impl Fn(Point2) -> Rgb) -> Image {
+    let width = (max.x - min.x + 1).max(0) as usize;
+    let height = (max.y - min.y + 1).max(0) as usize;
+    Image::from_fn(width, height, |x, y| {
+        cell(Point2::new(min.x + x as i32, min.y + y as i32))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_to_image() {
+        let image = Image::from_text("#.\n.##\n", |ch| match ch {
+            '#' => [255, 255, 255],
+            _ => [0, 0, 0],
+        });
+        assert_eq!(3, image.width());
+        assert_eq!(2, image.height());
+        assert_eq!(
+            &[
+                [255, 255, 255],
+                [0, 0, 0],
+                [0, 0, 0],
+                [0, 0, 0],
+                [255, 255, 255],
+                [255, 255, 255]
+            ],
+            image.pixels()
+        );
+    }
+
+    #[test]
+    fn scaling_and_padding() {
+        let image = draw(Point2::new(1, 1), Point2::new(2, 1), |p| [p.x as u8; 3]);
+        let scaled = image.scaled(2);
+        assert_eq!((4, 2), (scaled.width(), scaled.height()));
+        assert_eq!([1; 3], scaled.pixels()[1]);
+        assert_eq!([2; 3], scaled.pixels()[6]);
+
+        let padded = image.padded(3, 2, [9; 3]);
+        assert_eq!(
+            &[[1; 3], [2; 3], [9; 3], [9; 3], [9; 3], [9; 3]],
+            padded.pixels()
+        );
+    }
+
+    #[test]
+    fn long_recordings_thin_out() {
+        let mut recording = Recording::new("test");
+        let mut rendered = 0;
+        for i in 0..MAX_FRAMES * 3 {
+            let frame = recording.wants_frame().then(|| {
+                rendered += 1;
+                Image::from_fn(1, 1, |_, _| [(i % 256) as u8; 3])
+            });
+            recording.add(frame);
+        }
+        assert!(recording.frames.len() <= MAX_FRAMES);
+        assert!(recording.frames.len() > MAX_FRAMES / 2);
+        assert_eq!(4, recording.step);
+        assert!(rendered < MAX_FRAMES * 2);
+        assert_eq!(
+            Some(Image::from_fn(1, 1, |_, _| [(896 % 256) as u8; 3])),
+            recording.last
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use std::{env, fmt::Display, num::NonZeroUsize};
+
+use crate::{
+    input, parallel,
+    visualize::{self, Settings},
+    ArgumentError, OrExit, ParseError,
+};
+
+// one day's puzzle: its input is parsed once and both parts answer from it
+pub trait Solution {
+    type Input<'a>;
+    // puzzle parameters that differ between the example and the real input
+    type Config: Default;
+    type Part1: Display;
+    type Part2: Display;
+    type Error: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part1(input: &Self::Input<'_>, config: &Self::Config) -> Result<Self::Part1, Self::Error>;
+    fn part2(input: &Self::Input<'_>, config: &Self::Config) -> Result<Self::Part2, Self::Error>;
+}
+
+// the body of each day's binary: both answers for the bundled input or the one given,
+// animating the simulation with --visualize and working on as many as --threads
+pub fn run<S: Solution>(bundled: &'static str) {
+    run_with::<S>(take_options(env::args().skip(1)), bundled);
+}
+
+// the answers for the arguments `take_options` left, for binaries that look for
+// more options of their own first
+pub fn run_with<S: Solution>(args: Vec<String>, bundled: &'static str) {
+    let input = input::from_args(args.into_iter(), bundled).or_exit();
+    let input = S::parse(&input).or_exit();
+    let config = S::Config::default();
+    println!("{}", S::part1(&input, &config).or_exit());
+    println!("{}", S::part2(&input, &config).or_exit());
+}
+
+// the options every day binary takes, wherever they are among its arguments
+#[derive(Debug, Clone, PartialEq)]
+pub struct Options {
+    pub visualize: Option<Settings>,
+    pub threads: Option<NonZeroUsize>,
+    // the arguments left in their order
+    pub rest: Vec<String>,
+}
+
+pub fn options_from_args<I>(args: I) -> Result<Options, ArgumentError>
+where
+    I: Iterator<Item = String>,
+{
+    let (visualize, args) = visualize::from_args(args)?;
+    let (threads, rest) = parallel::from_args(args.into_iter())?;
+    Ok(Options {
+        visualize,
+        threads,
+        rest,
+    })
+}
+
+// sets up --visualize, --fps, --paused and --threads for the rest of the run and
+// returns the other arguments, for binaries with commands besides the answers
+pub fn take_options<I>(args: I) -> Vec<String>
+where
+    I: Iterator<Item = String>,
+{
+    let options = options_from_args(args).or_exit();
+    if let Some(settings) = options.visualize {
+        visualize::enable(settings);
+    }
+    if let Some(threads) = options.threads {
+        parallel::set_threads(threads);
+    }
+    options.rest
+}
+
+// for parts whose search can come up empty
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoAnswer;
+
+impl Display for NoAnswer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "no answer")
+    }
+}
+
+impl std::error::Error for NoAnswer {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        args.iter()
+            .map(|arg| arg.to_string())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn options_among_commands() {
+        let options = options_from_args(args(&["--trace", "--threads", "2", "in.txt"])).unwrap();
+        assert_eq!(None, options.visualize);
+        assert_eq!(NonZeroUsize::new(2), options.threads);
+        assert_eq!(vec!["--trace", "in.txt"], options.rest);
+
+        let options = options_from_args(args(&["--summary", "--visualize", "-"])).unwrap();
+        assert_eq!(Some(Settings::default()), options.visualize);
+        assert_eq!(None, options.threads);
+        assert_eq!(vec!["--summary", "-"], options.rest);
+
+        assert_eq!(
+            Err(ArgumentError::MissingValue("--threads".into())),
+            options_from_args(args(&["--summary", "--threads"]))
+        );
+    }
+}
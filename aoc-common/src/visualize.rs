@@ -0,0 +1,327 @@
+use std::{
+    fmt::Display,
+    io::{self, BufRead, IsTerminal, Write},
+    sync::{
+        mpsc::{self, Receiver, RecvTimeoutError},
+        Mutex, OnceLock,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{ArgumentError, Point2};
+
+pub const DEFAULT_FPS: f64 = 10.0;
+
+const CLEAR_SCREEN: &str = "\x1b[H\x1b[2J";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Settings {
+    pub fps: f64,
+    pub paused: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            fps: DEFAULT_FPS,
+            paused: false,
+        }
+    }
+}
+
+fn parse_fps(value: &str) -> Option<f64> {
+    value
+        .parse()
+        .ok()
+        .filter(|fps: &f64| fps.is_finite() && *fps > 0.0)
+}
+
+// takes --visualize, --fps <N> and --paused out of a day binary's arguments,
+// the settings are only returned when --visualize was given
+pub fn from_args<I>(mut args: I) -> Result<(Option<Settings>, Vec<String>), ArgumentError>
+where
+    I: Iterator<Item = String>,
+{
+    let mut visualize = false;
+    let mut settings = Settings::default();
+    let mut rest = vec![];
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--visualize" => visualize = true,
+            "--paused" => settings.paused = true,
+            "--fps" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| ArgumentError::MissingValue(arg.clone()))?;
+                settings.fps = parse_fps(&value).ok_or(ArgumentError::InvalidValue(arg, value))?;
+            }
+            _ => rest.push(arg),
+        }
+    }
+    Ok((visualize.then_some(settings), rest))
+}
+
+// typed into the terminal followed by enter, as the standard library has no raw mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Control {
+    Step,
+    Pause,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl Control {
+    fn parse(line: &str) -> Option<Control> {
+        match line.trim() {
+            "" | "n" => Some(Control::Step),
+            "p" => Some(Control::Pause),
+            "+" => Some(Control::Faster),
+            "-" => Some(Control::Slower),
+            "q" => Some(Control::Quit),
+            _ => None,
+        }
+    }
+}
+
+const CONTROLS_HELP: &str = "enter: step  p: pause/resume  +/-: speed  q: skip to the end";
+
+fn read_controls() -> Receiver<Control> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else { break };
+            if let Some(control) = Control::parse(&line) {
+                if sender.send(control).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+    receiver
+}
+
+struct Animation {
+    fps: f64,
+    paused: bool,
+    finished: bool,
+    frames: usize,
+    controls: Option<Receiver<Control>>,
+}
+
+impl Animation {
+    fn new(settings: Settings) -> Animation {
+        // the controls need the terminal, not an input piped into standard input
+        let controls = io::stdin().is_terminal().then(read_controls);
+        Animation {
+            fps: settings.fps,
+            paused: settings.paused && controls.is_some(),
+            finished: false,
+            frames: 0,
+            controls,
+        }
+    }
+
+    fn show(&mut self, title: &str, frame: &str) {
+        self.frames += 1;
+        let status = format!("frame {}  {} fps", self.frames, self.fps);
+        let help = match self.controls {
+            Some(_) => CONTROLS_HELP,
+            None => "",
+        };
+        let mut stdout = io::stdout().lock();
+        let _ = write!(
+            stdout,
+            "{}",
+            render(title, frame, &status, help, self.paused)
+        );
+        let _ = stdout.flush();
+        drop(stdout);
+        self.wait();
+    }
+
+    fn wait(&mut self) {
+        let deadline = Instant::now() + Duration::from_secs_f64(1.0 / self.fps);
+        loop {
+            let Some(controls) = &self.controls else {
+                thread::sleep(deadline.saturating_duration_since(Instant::now()));
+                return;
+            };
+            let control = if self.paused {
+                controls.recv().map_err(|_| RecvTimeoutError::Disconnected)
+            } else {
+                controls.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            };
+            match control {
+                Err(RecvTimeoutError::Timeout) => return,
+                Err(RecvTimeoutError::Disconnected) => {
+                    self.controls = None;
+                    self.paused = false;
+                }
+                Ok(Control::Step) => {
+                    self.paused = true;
+                    return;
+                }
+                Ok(Control::Pause) => self.paused = !self.paused,
+                Ok(Control::Faster) => self.fps *= 2.0,
+                Ok(Control::Slower) => self.fps /= 2.0,
+                Ok(Control::Quit) => {
+                    self.finished = true;
+                    return;
+                }
+            }
+        }
+    }
+}
+
+fn render(title: &str, frame: &str, status: &str, help: &str, paused: bool) -> String {
+    let mut screen = format!("{}{}\n\n{}", CLEAR_SCREEN, title, frame);
+    if !frame.ends_with('\n') {
+        screen.push('\n');
+    }
+    screen.push('\n');
+    screen.push_str(status);
+    if paused {
+        screen.push_str("  (paused)");
+    }
+    if !help.is_empty() {
+        screen.push('\n');
+        screen.push_str(help);
+    }
+    screen.push('\n');
+    screen
+}
+
+// the most of a grid that is drawn, to keep frames within a terminal
+pub const MAX_WIDTH: i32 = 120;
+pub const MAX_HEIGHT: i32 = 40;
+
+fn clip(min: i32, max: i32, focus: i32, size: i32) -> (i32, i32) {
+    if max - min < size {
+        return (min, max);
+    }
+    let start = (focus - size / 2).clamp(min, max - size + 1);
+    (start, start + size - 1)
+}
+
+// the part of the bounds `min` to `max` that fits the screen, with `focus` in view
+pub fn window(min: Point2, max: Point2, focus: Point2) -> (Point2, Point2) {
+    let (min_x, max_x) = clip(min.x, max.x, focus.x, MAX_WIDTH);
+    let (min_y, max_y) = clip(min.y, max.y, focus.y, MAX_HEIGHT);
+    (Point2::new(min_x, min_y), Point2::new(max_x, max_y))
+}
+
+// the rows from `min` to `max` inclusive, one character per position
+pub fn draw(min: Point2, max: Point2, cell: impl Fn(Point2) -> char) -> String {
+    let mut text = String::new();
+    for y in min.y..=max.y {
+        text.extend((min.x..=max.x).map(|x| cell(Point2::new(x, y))));
+        text.push('\n');
+    }
+    text
+}
+
+static ANIMATION: OnceLock<Mutex<Animation>> = OnceLock::new();
+
+pub fn enable(settings: Settings) {
+    let _ = ANIMATION.set(Mutex::new(Animation::new(settings)));
+}
+
+pub fn enabled() -> bool {
+    ANIMATION.get().is_some()
+}
+
+// one step of a simulation; `render` only runs while an animation is being shown,
+// so solvers can call this in their inner loops
+pub fn frame<R: FnOnce() -> String>(title: impl Display, render: R) {
+    if let Some(animation) = ANIMATION.get() {
+        let mut animation = animation.lock().unwrap();
+        if !animation.finished {
+            animation.show(&title.to_string(), &render());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        args.iter()
+            .map(|arg| arg.to_string())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn visualize_arguments() {
+        let (settings, rest) = from_args(args(&["input.txt"])).unwrap();
+        assert_eq!(None, settings);
+        assert_eq!(vec!["input.txt"], rest);
+
+        let (settings, rest) =
+            from_args(args(&["--visualize", "-", "--fps", "2.5", "--paused"])).unwrap();
+        assert_eq!(
+            Some(Settings {
+                fps: 2.5,
+                paused: true
+            }),
+            settings
+        );
+        assert_eq!(vec!["-"], rest);
+
+        assert_eq!(
+            Err(ArgumentError::InvalidValue("--fps".into(), "0".into())),
+            from_args(args(&["--visualize", "--fps", "0"]))
+        );
+        assert_eq!(
+            Err(ArgumentError::MissingValue("--fps".into())),
+            from_args(args(&["--fps"]))
+        );
+    }
+
+    #[test]
+    fn controls() {
+        assert_eq!(Some(Control::Step), Control::parse(""));
+        assert_eq!(Some(Control::Pause), Control::parse("p\n"));
+        assert_eq!(Some(Control::Quit), Control::parse(" q "));
+        assert_eq!(None, Control::parse("x"));
+    }
+
+    #[test]
+    fn drawing() {
+        let text = draw(Point2::new(-1, 0), Point2::new(1, 1), |p| {
+            if p.x == p.y {
+                '#'
+            } else {
+                '.'
+            }
+        });
+        assert_eq!(".#.\n..#\n", text);
+    }
+
+    #[test]
+    fn windows() {
+        let (min, max) = (Point2::new(0, 0), Point2::new(9, 9));
+        assert_eq!((min, max), window(min, max, Point2::new(5, 5)));
+
+        let max = Point2::new(999, 9);
+        let (from, to) = window(min, max, Point2::new(500, 5));
+        assert_eq!(Point2::new(440, 0), from);
+        assert_eq!(Point2::new(440 + MAX_WIDTH - 1, 9), to);
+        let (from, _) = window(min, max, Point2::new(3, 5));
+        assert_eq!(Point2::new(0, 0), from);
+        let (_, to) = window(min, max, Point2::new(998, 5));
+        assert_eq!(max, to);
+    }
+
+    #[test]
+    fn screen() {
+        let screen = render("Round 1", "#.\n.#", "frame 1  10 fps", "", true);
+        assert_eq!(
+            "\x1b[H\x1b[2JRound 1\n\n#.\n.#\n\nframe 1  10 fps  (paused)\n",
+            screen
+        );
+    }
+}
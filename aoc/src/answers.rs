@@ -0,0 +1,155 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use aoc_common::parse::{lines, ParseError};
+
+use crate::puzzles::Part;
+
+pub const HEADER: &str = "# input\tday\tpart\tanswer";
+
+// FNV-1a, so the keys stay the same across Rust releases unlike DefaultHasher;
+// trailing whitespace is ignored as editors and downloads disagree about the last newline
+pub fn input_hash(input: &str) -> String {
+    let hash = input
+        .trim_end()
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+    format!("{:016x}", hash)
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut out = String::new();
+    let mut chars = answer.chars();
+    while let Some(ch) = chars.next() {
+        match (ch, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                out.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                out.push('\\');
+                chars.next();
+            }
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail(String),
+    Unknown,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Pass => f.pad("pass"),
+            Outcome::Fail(_) => f.pad("fail"),
+            Outcome::Unknown => f.pad("unknown"),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(String, u8, Part), String>,
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Answers, ParseError> {
+        let mut answers = Answers::default();
+        for line in lines(input).filter(|line| !line.text.is_empty() && !line.text.starts_with('#'))
+        {
+            let fields = line.text.splitn(4, '\t').collect::<Vec<_>>();
+            let [hash, day, part, answer] = fields[..] else {
+                return Err(line.error(format!("expected 4 fields, found {}", fields.len())));
+            };
+            let day = day
+                .parse()
+                .map_err(|err| line.error(format!("invalid day {:?}: {}", day, err)))?;
+            let part = part.parse().map_err(|err| line.error(err))?;
+            answers.insert(hash, day, part, &unescape(answer));
+        }
+        Ok(answers)
+    }
+
+    pub fn get(&self, hash: &str, day: u8, part: Part) -> Option<&str> {
+        self.answers
+            .get(&(hash.to_string(), day, part))
+            .map(String::as_str)
+    }
+
+    pub fn insert(&mut self, hash: &str, day: u8, part: Part, answer: &str) {
+        self.answers
+            .insert((hash.to_string(), day, part), answer.to_string());
+    }
+
+    pub fn check(&self, hash: &str, day: u8, part: Part, answer: &str) -> Outcome {
+        match self.get(hash, day, part) {
+            Some(expected) if expected == answer => Outcome::Pass,
+            Some(expected) => Outcome::Fail(expected.to_string()),
+            None => Outcome::Unknown,
+        }
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", HEADER)?;
+        for ((hash, day, part), answer) in &self.answers {
+            writeln!(f, "{}\t{}\t{}\t{}", hash, day, part, escape(answer))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stable_input_hash() {
+        assert_eq!("cbf29ce484222325", input_hash(""));
+        assert_eq!("af63dc4c8601ec8c", input_hash("a"));
+        assert_eq!(input_hash("1\n2\n3"), input_hash("1\n2\n3\n"));
+        assert_ne!(input_hash("1\n2\n3"), input_hash("1\n2\n4"));
+    }
+
+    #[test]
+    fn answers_round_trip() {
+        let mut answers = Answers::default();
+        answers.insert("00ff", 1, Part::A, "72511");
+        answers.insert("00ff", 10, Part::B, "#..#\n\\.#.");
+        let text = answers.to_string();
+        assert!(text.starts_with(HEADER));
+        assert!(text.contains("00ff\t10\tb\t#..#\\n\\\\.#.\n"));
+        assert_eq!(Ok(answers), Answers::parse(&text));
+
+        let err = Answers::parse("00ff\t1\tc\t1\n").unwrap_err();
+        assert_eq!(1, err.line());
+    }
+
+    #[test]
+    fn pass_fail_unknown() {
+        let mut answers = Answers::default();
+        answers.insert("00ff", 5, Part::B, "NLCDCLVMQ");
+        assert_eq!(
+            Outcome::Pass,
+            answers.check("00ff", 5, Part::B, "NLCDCLVMQ")
+        );
+        assert_eq!(
+            Outcome::Fail(String::from("NLCDCLVMQ")),
+            answers.check("00ff", 5, Part::B, "CMZ")
+        );
+        assert_eq!(Outcome::Unknown, answers.check("00ff", 5, Part::A, "CMZ"));
+        assert_eq!(Outcome::Unknown, answers.check("1234", 5, Part::B, "CMZ"));
+    }
+}
@@ -0,0 +1,655 @@
+use std::{fmt::Display, num::NonZeroUsize, ops::RangeInclusive, str::FromStr};
+
+use aoc_common::visualize::{self, Settings};
+
+use crate::puzzles::Part;
+
+pub const USAGE: &str = "\
+Usage: aoc run <DAYS> [PART] [--input <PATH>] [--fetch] [--visualize [--fps <N>] [--paused]]
+               [--render <DIR>] [--threads <N>]
+       aoc bench <DAYS> [PART] [--iterations <N>] [--input <PATH>] [--output <FILE>]
+                 [--threads <N>]
+       aoc compare <BASELINE> <CURRENT> [--threshold <PERCENT>]
+       aoc verify <DAYS> [PART] [--answers <FILE>] [--record]
+       aoc fetch <DAYS> [PART]
+       aoc submit <DAY><PART> [ANSWER]
+       aoc example <DAY><PART> <HTML> [--force]
+
+  DAYS                   a day number (16), a range (1..=25 or 1..25) or `all`,
+                         optionally followed by the part (19a)
+  PART                   `a` or `b`, both parts are run when omitted
+  --input <PATH>         read the puzzle input from PATH, or standard input for `-`,
+                         instead of each day's src/input.txt
+  --fetch                download the src/input.txt files that are missing
+  --visualize            animate the simulations of days 9, 14, 17, 22, 23 and 24
+                         in the terminal; while it runs, enter steps a frame, and
+                         p, +, - or q followed by enter pause, speed up, slow down
+                         or skip to the end
+  --fps <N>              frames per second of the animation, 10 by default
+  --paused               start the animation paused, to step through it
+  --render <DIR>         save the grids of days 10, 14, 17, 23 and 24 to DIR, the
+                         last one as PPM and PNG images and all as an animated GIF;
+                         needs a build with `--features render`
+  --threads <N>          threads for the puzzles that work in parallel (days 15, 16
+                         and 19), AOC_THREADS or the number of CPUs by default
+  --iterations <N>       number of timed runs per puzzle, 10 by default
+  --output <FILE>        where to write the benchmark results, bench.tsv by default
+  BASELINE, CURRENT      results files written by two `aoc bench` runs
+  --threshold <PERCENT>  slowdown of the median above which a puzzle counts as a
+                         regression, 10 by default
+  --answers <FILE>       the known answers, answers.tsv in the workspace by default
+  --record               add the answers of inputs without one to the answers file
+  ANSWER                 the answer to submit, the puzzle is solved when omitted
+  HTML                   a saved puzzle description page to take the example from
+  --force                overwrite an existing src/example.txt
+
+The fetch and submit commands and --fetch read the session token from aoc.conf
+in the workspace (or the file named by AOC_CONFIG) or the AOC_SESSION variable.";
+
+pub const DEFAULT_ITERATIONS: usize = 10;
+pub const DEFAULT_OUTPUT: &str = "bench.tsv";
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+const ALL_DAYS: RangeInclusive<u8> = 1..=25;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Selection {
+    pub days: RangeInclusive<u8>,
+    pub part: Option<Part>,
+}
+
+impl Selection {
+    pub fn contains(&self, day: u8, part: Part) -> bool {
+        self.days.contains(&day) && self.part.is_none_or(|p| p == part)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        selection: Selection,
+        input: Option<String>,
+        fetch: bool,
+        visualize: Option<Settings>,
+        render: Option<String>,
+        threads: Option<NonZeroUsize>,
+    },
+    Bench {
+        selection: Selection,
+        input: Option<String>,
+        iterations: usize,
+        output: String,
+        threads: Option<NonZeroUsize>,
+    },
+    Compare {
+        baseline: String,
+        current: String,
+        threshold: f64,
+    },
+    Verify {
+        selection: Selection,
+        answers: Option<String>,
+        record: bool,
+    },
+    Fetch {
+        selection: Selection,
+    },
+    Submit {
+        day: u8,
+        part: Part,
+        answer: Option<String>,
+    },
+    Example {
+        day: u8,
+        part: Part,
+        html: String,
+        force: bool,
+    },
+    Help,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum UsageError {
+    MissingCommand,
+    UnknownCommand(String),
+    MissingDays,
+    MissingFile,
+    MissingPuzzle,
+    MissingValue(String),
+    InvalidValue(String, String),
+    InvalidDays(String),
+    InvalidPart(String),
+    UnexpectedArgument(String),
+}
+
+impl Display for UsageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingCommand => write!(f, "No command given"),
+            Self::UnknownCommand(cmd) => write!(f, "Unknown command: {}", cmd),
+            Self::MissingDays => write!(f, "No days given"),
+            Self::MissingFile => write!(f, "No file given"),
+            Self::MissingPuzzle => write!(f, "No single day and part given, such as 19a"),
+            Self::MissingValue(option) => write!(f, "No value given for {}", option),
+            Self::InvalidValue(option, value) => {
+                write!(f, "Invalid value for {}: {}", option, value)
+            }
+            Self::InvalidDays(days) => write!(f, "Invalid days: {}", days),
+            Self::InvalidPart(part) => write!(f, "Invalid part: {}", part),
+            Self::UnexpectedArgument(arg) => write!(f, "Unexpected argument: {}", arg),
+        }
+    }
+}
+
+fn parse_day(s: &str) -> Option<u8> {
+    s.parse().ok().filter(|day| ALL_DAYS.contains(day))
+}
+
+fn parse_days(s: &str) -> Result<RangeInclusive<u8>, UsageError> {
+    let invalid = || UsageError::InvalidDays(s.to_string());
+    let days = if s == "all" {
+        ALL_DAYS
+    } else if let Some((start, end)) = s.split_once("..=") {
+        parse_day(start).ok_or_else(invalid)?..=parse_day(end).ok_or_else(invalid)?
+    } else if let Some((start, end)) = s.split_once("..") {
+        let end = end
+            .parse::<u8>()
+            .ok()
+            .and_then(|end| end.checked_sub(1))
+            .filter(|end| ALL_DAYS.contains(end))
+            .ok_or_else(invalid)?;
+        parse_day(start).ok_or_else(invalid)?..=end
+    } else {
+        let day = parse_day(s).ok_or_else(invalid)?;
+        day..=day
+    };
+    if days.is_empty() {
+        Err(invalid())
+    } else {
+        Ok(days)
+    }
+}
+
+fn parse_part(s: &str) -> Result<Part, UsageError> {
+    match s {
+        "a" | "1" => Ok(Part::A),
+        "b" | "2" => Ok(Part::B),
+        _ => Err(UsageError::InvalidPart(s.to_string())),
+    }
+}
+
+// a day can carry its part as a suffix, as in `19a`
+fn parse_days_and_part(s: &str) -> Result<(RangeInclusive<u8>, Option<Part>), UsageError> {
+    for (suffix, part) in [("a", Part::A), ("b", Part::B)] {
+        if let Some(days) = s
+            .strip_suffix(suffix)
+            .and_then(|days| parse_days(days).ok())
+        {
+            return Ok((days, Some(part)));
+        }
+    }
+    parse_days(s).map(|days| (days, None))
+}
+
+fn parse_selection(positional: &[&str]) -> Result<Selection, UsageError> {
+    let mut positional = positional.iter();
+    let (days, part) = parse_days_and_part(positional.next().ok_or(UsageError::MissingDays)?)?;
+    let part = match part {
+        Some(part) => Some(part),
+        None => positional.next().map(|part| parse_part(part)).transpose()?,
+    };
+    match positional.next() {
+        Some(arg) => Err(UsageError::UnexpectedArgument(arg.to_string())),
+        None => Ok(Selection { days, part }),
+    }
+}
+
+struct Arguments<'a> {
+    positional: Vec<&'a str>,
+    options: Vec<(&'a str, &'a str)>,
+    flags: Vec<&'a str>,
+}
+
+impl<'a> Arguments<'a> {
+    fn split<I: Iterator<Item = &'a str>>(
+        mut args: I,
+        options: &[&str],
+        flags: &[&str],
+    ) -> Result<Arguments<'a>, UsageError> {
+        let mut arguments = Arguments {
+            positional: vec![],
+            options: vec![],
+            flags: vec![],
+        };
+        while let Some(arg) = args.next() {
+            if options.contains(&arg) {
+                let value = args
+                    .next()
+                    .ok_or_else(|| UsageError::MissingValue(arg.to_string()))?;
+                arguments.options.push((arg, value));
+            } else if flags.contains(&arg) {
+                arguments.flags.push(arg);
+            } else {
+                arguments.positional.push(arg);
+            }
+        }
+        Ok(arguments)
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.flags.contains(&name)
+    }
+
+    fn option(&self, name: &str) -> Option<&'a str> {
+        self.options
+            .iter()
+            .rev()
+            .find(|(option, _)| *option == name)
+            .map(|(_, value)| *value)
+    }
+
+    fn parsed_option<T: FromStr>(
+        &self,
+        name: &str,
+        valid: impl Fn(&T) -> bool,
+    ) -> Result<Option<T>, UsageError> {
+        self.option(name)
+            .map(|value| {
+                value
+                    .parse()
+                    .ok()
+                    .filter(|v| valid(v))
+                    .ok_or_else(|| UsageError::InvalidValue(name.to_string(), value.to_string()))
+            })
+            .transpose()
+    }
+}
+
+fn parse_run<'a, I: Iterator<Item = &'a str>>(args: I) -> Result<Command, UsageError> {
+    let args = Arguments::split(
+        args,
+        &["--input", "--fps", "--render", "--threads"],
+        &["--fetch", "--visualize", "--paused"],
+    )?;
+    let fps = args.parsed_option("--fps", |fps: &f64| fps.is_finite() && *fps > 0.0)?;
+    Ok(Command::Run {
+        selection: parse_selection(&args.positional)?,
+        input: args.option("--input").map(String::from),
+        fetch: args.flag("--fetch"),
+        visualize: args.flag("--visualize").then(|| Settings {
+            fps: fps.unwrap_or(visualize::DEFAULT_FPS),
+            paused: args.flag("--paused"),
+        }),
+        render: args.option("--render").map(String::from),
+        threads: args.parsed_option("--threads", |_| true)?,
+    })
+}
+
+fn parse_bench<'a, I: Iterator<Item = &'a str>>(args: I) -> Result<Command, UsageError> {
+    let args = Arguments::split(
+        args,
+        &["--input", "--iterations", "--output", "--threads"],
+        &[],
+    )?;
+    Ok(Command::Bench {
+        selection: parse_selection(&args.positional)?,
+        input: args.option("--input").map(String::from),
+        iterations: args
+            .parsed_option("--iterations", |&n| n > 0)?
+            .unwrap_or(DEFAULT_ITERATIONS),
+        output: args
+            .option("--output")
+            .unwrap_or(DEFAULT_OUTPUT)
+            .to_string(),
+        threads: args.parsed_option("--threads", |_| true)?,
+    })
+}
+
+fn parse_compare<'a, I: Iterator<Item = &'a str>>(args: I) -> Result<Command, UsageError> {
+    let args = Arguments::split(args, &["--threshold"], &[])?;
+    match args.positional[..] {
+        [baseline, current] => Ok(Command::Compare {
+            baseline: baseline.to_string(),
+            current: current.to_string(),
+            threshold: args
+                .parsed_option("--threshold", |t: &f64| t.is_finite() && *t >= 0.0)?
+                .unwrap_or(DEFAULT_THRESHOLD),
+        }),
+        [_, _, extra, ..] => Err(UsageError::UnexpectedArgument(extra.to_string())),
+        _ => Err(UsageError::MissingFile),
+    }
+}
+
+fn parse_verify<'a, I: Iterator<Item = &'a str>>(args: I) -> Result<Command, UsageError> {
+    let args = Arguments::split(args, &["--answers"], &["--record"])?;
+    Ok(Command::Verify {
+        selection: parse_selection(&args.positional)?,
+        answers: args.option("--answers").map(String::from),
+        record: args.flag("--record"),
+    })
+}
+
+fn parse_fetch<'a, I: Iterator<Item = &'a str>>(args: I) -> Result<Command, UsageError> {
+    let args = Arguments::split(args, &[], &[])?;
+    Ok(Command::Fetch {
+        selection: parse_selection(&args.positional)?,
+    })
+}
+
+// a single day and part, such as 19a
+fn parse_puzzle(s: Option<&str>) -> Result<(u8, Part), UsageError> {
+    match parse_days_and_part(s.ok_or(UsageError::MissingPuzzle)?)? {
+        (days, Some(part)) if days.start() == days.end() => Ok((*days.start(), part)),
+        _ => Err(UsageError::MissingPuzzle),
+    }
+}
+
+fn parse_submit<'a, I: Iterator<Item = &'a str>>(args: I) -> Result<Command, UsageError> {
+    let args = Arguments::split(args, &[], &[])?;
+    let mut positional = args.positional.into_iter();
+    let (day, part) = parse_puzzle(positional.next())?;
+    let answer = positional.next().map(String::from);
+    match positional.next() {
+        Some(arg) => Err(UsageError::UnexpectedArgument(arg.to_string())),
+        None => Ok(Command::Submit { day, part, answer }),
+    }
+}
+
+fn parse_example<'a, I: Iterator<Item = &'a str>>(args: I) -> Result<Command, UsageError> {
+    let args = Arguments::split(args, &[], &["--force"])?;
+    let mut positional = args.positional.iter().copied();
+    let (day, part) = parse_puzzle(positional.next())?;
+    let html = positional.next().ok_or(UsageError::MissingFile)?;
+    match positional.next() {
+        Some(arg) => Err(UsageError::UnexpectedArgument(arg.to_string())),
+        None => Ok(Command::Example {
+            day,
+            part,
+            html: html.to_string(),
+            force: args.flag("--force"),
+        }),
+    }
+}
+
+pub fn parse_args<S: AsRef<str>>(args: &[S]) -> Result<Command, UsageError> {
+    let mut args = args.iter().map(AsRef::as_ref);
+    match args.next() {
+        Some("run") => parse_run(args),
+        Some("bench") => parse_bench(args),
+        Some("compare") => parse_compare(args),
+        Some("verify") => parse_verify(args),
+        Some("fetch") => parse_fetch(args),
+        Some("submit") => parse_submit(args),
+        Some("example") => parse_example(args),
+        Some("help" | "-h" | "--help") => match args.next() {
+            Some(arg) => Err(UsageError::UnexpectedArgument(arg.to_string())),
+            None => Ok(Command::Help),
+        },
+        Some(cmd) => Err(UsageError::UnknownCommand(cmd.to_string())),
+        None => Err(UsageError::MissingCommand),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(days: RangeInclusive<u8>, part: Option<Part>) -> Result<Command, UsageError> {
+        Ok(Command::Run {
+            selection: Selection { days, part },
+            input: None,
+            fetch: false,
+            visualize: None,
+            render: None,
+            threads: None,
+        })
+    }
+
+    #[test]
+    fn single_day_and_part() {
+        assert_eq!(run(16..=16, Some(Part::B)), parse_args(&["run", "16", "b"]));
+        assert_eq!(run(3..=3, None), parse_args(&["run", "3"]));
+        assert_eq!(run(19..=19, Some(Part::A)), parse_args(&["run", "19a"]));
+    }
+
+    #[test]
+    fn ranges_of_days() {
+        assert_eq!(run(1..=25, None), parse_args(&["run", "1..=25"]));
+        assert_eq!(run(1..=24, None), parse_args(&["run", "1..25"]));
+        assert_eq!(run(20..=25, None), parse_args(&["run", "20..26"]));
+        assert_eq!(run(1..=25, Some(Part::A)), parse_args(&["run", "all", "a"]));
+    }
+
+    #[test]
+    fn input_option() {
+        assert_eq!(
+            Ok(Command::Run {
+                selection: Selection {
+                    days: 5..=5,
+                    part: Some(Part::A)
+                },
+                input: Some(String::from("-")),
+                fetch: false,
+                visualize: None,
+                render: None,
+                threads: None,
+            }),
+            parse_args(&["run", "5", "--input", "-", "a"])
+        );
+        assert_eq!(
+            Err(UsageError::MissingValue(String::from("--input"))),
+            parse_args(&["run", "5", "--input"])
+        );
+    }
+
+    #[test]
+    fn threads_option() {
+        assert!(matches!(
+            parse_args(&["run", "19", "--threads", "2"]),
+            Ok(Command::Run { threads: Some(threads), .. }) if threads.get() == 2
+        ));
+        assert!(matches!(
+            parse_args(&["bench", "15..=16", "--threads", "8"]),
+            Ok(Command::Bench { threads: Some(threads), .. }) if threads.get() == 8
+        ));
+        assert_eq!(
+            Err(UsageError::InvalidValue(
+                String::from("--threads"),
+                String::from("0")
+            )),
+            parse_args(&["run", "19", "--threads", "0"])
+        );
+    }
+
+    #[test]
+    fn bench_options() {
+        assert_eq!(
+            Ok(Command::Bench {
+                selection: Selection {
+                    days: 19..=19,
+                    part: Some(Part::A)
+                },
+                input: None,
+                iterations: 20,
+                output: String::from(DEFAULT_OUTPUT),
+                threads: None,
+            }),
+            parse_args(&["bench", "19a", "--iterations", "20"])
+        );
+        assert_eq!(
+            Ok(Command::Bench {
+                selection: Selection {
+                    days: 1..=25,
+                    part: None
+                },
+                input: None,
+                iterations: DEFAULT_ITERATIONS,
+                output: String::from("before.tsv"),
+                threads: None,
+            }),
+            parse_args(&["bench", "all", "--output", "before.tsv"])
+        );
+        assert_eq!(
+            Err(UsageError::InvalidValue(
+                String::from("--iterations"),
+                String::from("0")
+            )),
+            parse_args(&["bench", "19", "--iterations", "0"])
+        );
+    }
+
+    #[test]
+    fn compare_files() {
+        assert_eq!(
+            Ok(Command::Compare {
+                baseline: String::from("before.tsv"),
+                current: String::from("after.tsv"),
+                threshold: 5.5,
+            }),
+            parse_args(&["compare", "before.tsv", "--threshold", "5.5", "after.tsv"])
+        );
+        assert_eq!(
+            Err(UsageError::MissingFile),
+            parse_args(&["compare", "before.tsv"])
+        );
+        assert_eq!(
+            Err(UsageError::UnexpectedArgument(String::from("x"))),
+            parse_args(&["compare", "a.tsv", "b.tsv", "x"])
+        );
+    }
+
+    #[test]
+    fn verify_options() {
+        assert_eq!(
+            Ok(Command::Verify {
+                selection: Selection {
+                    days: 1..=25,
+                    part: None
+                },
+                answers: None,
+                record: false,
+            }),
+            parse_args(&["verify", "all"])
+        );
+        assert_eq!(
+            Ok(Command::Verify {
+                selection: Selection {
+                    days: 5..=5,
+                    part: Some(Part::B)
+                },
+                answers: Some(String::from("mine.tsv")),
+                record: true,
+            }),
+            parse_args(&["verify", "5", "--record", "b", "--answers", "mine.tsv"])
+        );
+    }
+
+    #[test]
+    fn visualize_options() {
+        let visualize = |args: &[&str]| match parse_args(args) {
+            Ok(Command::Run { visualize, .. }) => visualize,
+            other => panic!("unexpected {:?}", other),
+        };
+        assert_eq!(None, visualize(&["run", "9", "--fps", "30"]));
+        assert_eq!(
+            Some(Settings::default()),
+            visualize(&["run", "9", "--visualize"])
+        );
+        assert_eq!(
+            Some(Settings {
+                fps: 30.0,
+                paused: true
+            }),
+            visualize(&["run", "9", "--visualize", "--fps", "30", "--paused"])
+        );
+        assert_eq!(
+            Err(UsageError::InvalidValue("--fps".into(), "0".into())),
+            parse_args(&["run", "9", "--visualize", "--fps", "0"])
+        );
+    }
+
+    #[test]
+    fn render_option() {
+        assert!(matches!(
+            parse_args(&["run", "14a", "--render", "out/"]),
+            Ok(Command::Run { render: Some(dir), .. }) if dir == "out/"
+        ));
+        assert_eq!(
+            Err(UsageError::MissingValue(String::from("--render"))),
+            parse_args(&["run", "14a", "--render"])
+        );
+    }
+
+    #[test]
+    fn client_commands() {
+        assert_eq!(
+            Ok(Command::Run {
+                selection: Selection {
+                    days: 1..=25,
+                    part: None
+                },
+                input: None,
+                fetch: true,
+                visualize: None,
+                render: None,
+                threads: None,
+            }),
+            parse_args(&["run", "all", "--fetch"])
+        );
+        assert_eq!(
+            Ok(Command::Submit {
+                day: 19,
+                part: Part::A,
+                answer: Some(String::from("600"))
+            }),
+            parse_args(&["submit", "19a", "600"])
+        );
+        assert_eq!(
+            Ok(Command::Submit {
+                day: 5,
+                part: Part::B,
+                answer: None
+            }),
+            parse_args(&["submit", "5b"])
+        );
+        assert_eq!(
+            Err(UsageError::MissingPuzzle),
+            parse_args(&["submit", "1..=3a", "1"])
+        );
+        assert_eq!(Err(UsageError::MissingPuzzle), parse_args(&["submit", "5"]));
+        assert_eq!(
+            Ok(Command::Example {
+                day: 5,
+                part: Part::A,
+                html: String::from("day05.html"),
+                force: true,
+            }),
+            parse_args(&["example", "5a", "day05.html", "--force"])
+        );
+    }
+
+    #[test]
+    fn invalid_arguments() {
+        assert_eq!(Err(UsageError::MissingCommand), parse_args::<&str>(&[]));
+        assert_eq!(
+            Err(UsageError::InvalidDays(String::from("26"))),
+            parse_args(&["run", "26"])
+        );
+        assert_eq!(
+            Err(UsageError::InvalidDays(String::from("5..=1"))),
+            parse_args(&["run", "5..=1"])
+        );
+        assert_eq!(
+            Err(UsageError::InvalidPart(String::from("c"))),
+            parse_args(&["run", "1", "c"])
+        );
+        assert_eq!(
+            Err(UsageError::UnexpectedArgument(String::from("x"))),
+            parse_args(&["run", "1", "a", "x"])
+        );
+        assert_eq!(
+            Err(UsageError::UnexpectedArgument(String::from("b"))),
+            parse_args(&["run", "1a", "b"])
+        );
+    }
+}
@@ -0,0 +1,265 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr, time::Duration};
+
+use aoc_common::parse::{lines, ParseError};
+
+use crate::puzzles::Part;
+
+pub const HEADER: &str = "# day\tpart\tphase\titerations\tmin_ns\tmedian_ns\tmax_ns";
+
+pub struct Sample {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    Solve,
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => f.pad("parse"),
+            Phase::Solve => f.pad("solve"),
+        }
+    }
+}
+
+impl FromStr for Phase {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Phase::Parse),
+            "solve" => Ok(Phase::Solve),
+            _ => Err(format!("unknown phase {:?}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn new(mut times: Vec<Duration>) -> Stats {
+        assert!(!times.is_empty(), "no times to summarise");
+        times.sort();
+        let mid = times.len() / 2;
+        let median = if times.len().is_multiple_of(2) {
+            (times[mid - 1] + times[mid]) / 2
+        } else {
+            times[mid]
+        };
+        Stats {
+            min: times[0],
+            median,
+            max: times[times.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: Part,
+    pub phase: Phase,
+    pub iterations: usize,
+    pub stats: Stats,
+}
+
+impl Record {
+    fn key(&self) -> (u8, Part, Phase) {
+        (self.day, self.part, self.phase)
+    }
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.day,
+            self.part,
+            self.phase,
+            self.iterations,
+            self.stats.min.as_nanos(),
+            self.stats.median.as_nanos(),
+            self.stats.max.as_nanos()
+        )
+    }
+}
+
+impl FromStr for Record {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields = s.split('\t').collect::<Vec<_>>();
+        let [day, part, phase, iterations, min, median, max] = fields[..] else {
+            return Err(format!("expected 7 fields, found {}", fields.len()));
+        };
+        let nanos = |field: &str| {
+            field
+                .parse()
+                .map(Duration::from_nanos)
+                .map_err(|err| format!("invalid time {:?}: {}", field, err))
+        };
+        Ok(Record {
+            day: day
+                .parse()
+                .map_err(|err| format!("invalid day {:?}: {}", day, err))?,
+            part: part.parse()?,
+            phase: phase.parse()?,
+            iterations: iterations
+                .parse()
+                .map_err(|err| format!("invalid iterations {:?}: {}", iterations, err))?,
+            stats: Stats {
+                min: nanos(min)?,
+                median: nanos(median)?,
+                max: nanos(max)?,
+            },
+        })
+    }
+}
+
+pub fn format_results(records: &[Record]) -> String {
+    let mut out = format!("{}\n", HEADER);
+    for record in records {
+        out += &format!("{}\n", record);
+    }
+    out
+}
+
+pub fn parse_results(input: &str) -> Result<Vec<Record>, ParseError> {
+    lines(input)
+        .filter(|line| !line.text.is_empty() && !line.text.starts_with('#'))
+        .map(|line| line.parse())
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: Part,
+    pub phase: Phase,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Comparison {
+    // relative change of the median in percent, positive when the current run is slower
+    pub fn change(&self) -> f64 {
+        let baseline = self.baseline.as_secs_f64();
+        if baseline == 0.0 {
+            return 0.0;
+        }
+        (self.current.as_secs_f64() - baseline) / baseline * 100.0
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+}
+
+// medians of the puzzles and phases found in both runs
+pub fn compare(baseline: &[Record], current: &[Record]) -> Vec<Comparison> {
+    let baseline = baseline
+        .iter()
+        .map(|record| (record.key(), record.stats.median))
+        .collect::<BTreeMap<_, _>>();
+    let mut comparisons = current
+        .iter()
+        .filter_map(|record| {
+            baseline.get(&record.key()).map(|&median| Comparison {
+                day: record.day,
+                part: record.part,
+                phase: record.phase,
+                baseline: median,
+                current: record.stats.median,
+            })
+        })
+        .collect::<Vec<_>>();
+    comparisons.sort_by_key(|c| (c.day, c.part, c.phase));
+    comparisons
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(day: u8, part: Part, phase: Phase, median_ms: u64) -> Record {
+        Record {
+            day,
+            part,
+            phase,
+            iterations: 5,
+            stats: Stats {
+                min: Duration::from_millis(median_ms - 1),
+                median: Duration::from_millis(median_ms),
+                max: Duration::from_millis(median_ms + 1),
+            },
+        }
+    }
+
+    #[test]
+    fn min_median_max() {
+        let times = [7, 3, 5, 9, 1].map(Duration::from_millis).to_vec();
+        let stats = Stats::new(times.clone());
+        assert_eq!(Duration::from_millis(1), stats.min);
+        assert_eq!(Duration::from_millis(5), stats.median);
+        assert_eq!(Duration::from_millis(9), stats.max);
+        assert_eq!(
+            Duration::from_millis(6),
+            Stats::new(times[..4].to_vec()).median
+        );
+    }
+
+    #[test]
+    fn results_round_trip() {
+        let records = vec![
+            record(19, Part::A, Phase::Parse, 2),
+            record(19, Part::A, Phase::Solve, 150),
+        ];
+        let text = format_results(&records);
+        assert!(text.starts_with(HEADER));
+        assert!(text.contains("19\ta\tsolve\t5\t149000000\t150000000\t151000000\n"));
+        assert_eq!(Ok(records), parse_results(&text));
+
+        let err = parse_results("# header\n19\tc\tsolve\t5\t1\t2\t3\n").unwrap_err();
+        assert_eq!(2, err.line());
+    }
+
+    #[test]
+    fn regressions_above_threshold() {
+        let baseline = [
+            record(17, Part::B, Phase::Solve, 100),
+            record(19, Part::A, Phase::Solve, 100),
+            record(20, Part::A, Phase::Solve, 100),
+        ];
+        let current = [
+            record(19, Part::A, Phase::Solve, 125),
+            record(17, Part::B, Phase::Solve, 105),
+            record(21, Part::A, Phase::Solve, 100),
+        ];
+        let comparisons = compare(&baseline, &current);
+        assert_eq!(
+            vec![(17, 5.0), (19, 25.0)],
+            comparisons
+                .iter()
+                .map(|c| (c.day, c.change().round()))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![19],
+            comparisons
+                .iter()
+                .filter(|c| c.is_regression(10.0))
+                .map(|c| c.day)
+                .collect::<Vec<_>>()
+        );
+    }
+}
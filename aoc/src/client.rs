@@ -0,0 +1,416 @@
+use std::{
+    fmt::Display,
+    fs, io,
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    config::Config,
+    http::{self, HttpError, Method, Request, Response},
+    puzzles::Part,
+};
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    Http(HttpError),
+    Status(u16, String),
+    Cache(PathBuf, io::Error),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingSession => write!(
+                f,
+                "No session token, set `session` in aoc.conf or the AOC_SESSION variable"
+            ),
+            Self::Http(err) => write!(f, "{}", err),
+            Self::Status(status, body) => write!(f, "Server replied {}: {}", status, body),
+            Self::Cache(path, err) => write!(f, "Cannot cache {}: {}", path.display(), err),
+        }
+    }
+}
+
+impl From<HttpError> for ClientError {
+    fn from(err: HttpError) -> Self {
+        ClientError::Http(err)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect {
+        hint: Option<String>,
+        wait: Option<Duration>,
+    },
+    TooSoon(Duration),
+    AlreadySolved,
+    Unrecognised(String),
+}
+
+impl Verdict {
+    pub fn parse(html: &str) -> Verdict {
+        let text = article_text(html);
+        if text.contains("That's the right answer") {
+            Verdict::Correct
+        } else if text.contains("That's not the right answer") {
+            let hint = ["too high", "too low"]
+                .into_iter()
+                .find(|hint| text.contains(hint))
+                .map(String::from);
+            Verdict::Incorrect {
+                hint,
+                wait: retry_wait(&text),
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Verdict::TooSoon(retry_wait(&text).unwrap_or(Duration::from_secs(60)))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unrecognised(text)
+        }
+    }
+
+    fn wait(&self) -> Option<Duration> {
+        match self {
+            Verdict::Incorrect { wait, .. } => *wait,
+            Verdict::TooSoon(wait) => Some(*wait),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer"),
+            Verdict::Incorrect { hint, wait } => {
+                write!(f, "That's not the right answer")?;
+                if let Some(hint) = hint {
+                    write!(f, ", it's {}", hint)?;
+                }
+                if let Some(wait) = wait {
+                    write!(f, " (wait {}s before trying again)", wait.as_secs())?;
+                }
+                Ok(())
+            }
+            Verdict::TooSoon(wait) => {
+                write!(f, "Answered too recently, wait {}s", wait.as_secs())
+            }
+            Verdict::AlreadySolved => write!(f, "This part has already been solved"),
+            Verdict::Unrecognised(text) => write!(f, "Unrecognised reply: {}", text),
+        }
+    }
+}
+
+// the reply sits in the page's <article>, with the markup around the words dropped
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .map(|(_, rest)| rest.split("</article>").next().unwrap_or(rest))
+        .unwrap_or(html);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for ch in article.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// "You have 1m 3s left to wait", "Please wait one minute", "please wait 5 minutes"
+fn retry_wait(text: &str) -> Option<Duration> {
+    let lower = text.to_lowercase();
+    if let Some((before, _)) = lower.split_once(" left to wait") {
+        let (_, amount) = before.rsplit_once("you have ")?;
+        let seconds = amount
+            .split_whitespace()
+            .map(|part| {
+                let (number, unit) = part.split_at(part.find(|ch: char| !ch.is_ascii_digit())?);
+                let number = number.parse::<u64>().ok()?;
+                match unit {
+                    "h" => Some(number * 3600),
+                    "m" => Some(number * 60),
+                    "s" => Some(number),
+                    _ => None,
+                }
+            })
+            .sum::<Option<u64>>()?;
+        return Some(Duration::from_secs(seconds));
+    }
+    let (_, rest) = lower.split_once("wait ")?;
+    let mut words = rest.split_whitespace();
+    let number = match words.next()? {
+        "one" | "a" => 1,
+        number => number.parse().ok()?,
+    };
+    match words.next()? {
+        unit if unit.starts_with("minute") => Some(Duration::from_secs(number * 60)),
+        unit if unit.starts_with("second") => Some(Duration::from_secs(number)),
+        _ => None,
+    }
+}
+
+fn form_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                char::from(byte).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_secs())
+        .unwrap_or_default()
+}
+
+pub struct Client {
+    config: Config,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Client {
+        Client { config }
+    }
+
+    fn send(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<String>,
+    ) -> Result<String, ClientError> {
+        let session = self
+            .config
+            .session
+            .as_ref()
+            .ok_or(ClientError::MissingSession)?;
+        let mut headers = vec![
+            (String::from("Cookie"), format!("session={}", session)),
+            (String::from("User-Agent"), self.config.user_agent.clone()),
+        ];
+        if body.is_some() {
+            headers.push((
+                String::from("Content-Type"),
+                String::from("application/x-www-form-urlencoded"),
+            ));
+        }
+        let request = Request {
+            method,
+            url: format!("{}/{}{}", self.config.base_url, self.config.year, path),
+            headers,
+            body,
+        };
+        match http::send(&request)? {
+            Response { status: 200, body } => Ok(body),
+            Response { status, body } => Err(ClientError::Status(
+                status,
+                body.lines().next().unwrap_or_default().trim().to_string(),
+            )),
+        }
+    }
+
+    fn cache_path(&self, file_name: &str) -> PathBuf {
+        self.config
+            .cache_dir
+            .join(self.config.year.to_string())
+            .join(file_name)
+    }
+
+    fn write_cache(&self, path: &PathBuf, contents: &str) -> Result<(), ClientError> {
+        let cache_err = |err| ClientError::Cache(path.clone(), err);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(cache_err)?;
+        }
+        fs::write(path, contents).map_err(cache_err)
+    }
+
+    // inputs never change, so each is only downloaded once
+    pub fn input(&self, day: u8) -> Result<String, ClientError> {
+        let path = self.cache_path(&format!("day{:02}.txt", day));
+        if let Ok(input) = fs::read_to_string(&path) {
+            return Ok(input);
+        }
+        let input = self.send(Method::Get, &format!("/day/{}/input", day), None)?;
+        self.write_cache(&path, &input)?;
+        Ok(input)
+    }
+
+    fn submit_after(&self) -> PathBuf {
+        self.cache_path("submit-after")
+    }
+
+    // how long the server asked us to hold off after the last answer, if that isn't over yet
+    pub fn wait_remaining(&self) -> Option<Duration> {
+        let until = fs::read_to_string(self.submit_after())
+            .ok()?
+            .trim()
+            .parse::<u64>()
+            .ok()?;
+        until
+            .checked_sub(now())
+            .filter(|&secs| secs > 0)
+            .map(Duration::from_secs)
+    }
+
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Verdict, ClientError> {
+        if let Some(wait) = self.wait_remaining() {
+            return Ok(Verdict::TooSoon(wait));
+        }
+        let level = match part {
+            Part::A => 1,
+            Part::B => 2,
+        };
+        let body = format!("level={}&answer={}", level, form_encode(answer));
+        let html = self.send(Method::Post, &format!("/day/{}/answer", day), Some(body))?;
+        let verdict = Verdict::parse(&html);
+        if let Some(wait) = verdict.wait() {
+            let until = now() + wait.as_secs();
+            self.write_cache(&self.submit_after(), &until.to_string())?;
+        }
+        Ok(verdict)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    use super::*;
+
+    // a stand-in for adventofcode.com answering each connection with the next canned reply
+    fn serve(replies: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            replies
+                .into_iter()
+                .map(|(status, body)| {
+                    let (stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream);
+                    let mut request = String::new();
+                    let mut length = 0;
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if let Some(value) = line.strip_prefix("Content-Length: ") {
+                            length = value.trim().parse().unwrap();
+                        }
+                        request += &line;
+                        if line == "\r\n" {
+                            break;
+                        }
+                    }
+                    let mut body_bytes = vec![0; length];
+                    reader.read_exact(&mut body_bytes).unwrap();
+                    request += &String::from_utf8(body_bytes).unwrap();
+                    let reply = format!(
+                        "HTTP/1.1 {} X\r\nContent-Length: {}\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    );
+                    reader.get_mut().write_all(reply.as_bytes()).unwrap();
+                    request
+                })
+                .collect()
+        });
+        (base_url, handle)
+    }
+
+    fn stand_in_client(base_url: String, name: &str) -> Client {
+        let cache_dir = env::temp_dir().join(format!("aoc-client-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&cache_dir);
+        Client::new(Config {
+            session: Some(String::from("53616c7465")),
+            base_url,
+            year: 2022,
+            user_agent: String::from("test"),
+            cache_dir,
+        })
+    }
+
+    #[test]
+    fn input_is_downloaded_once() {
+        let (base_url, server) = serve(vec![(200, "1000\n2000\n\n3000\n")]);
+        let client = stand_in_client(base_url, "input");
+        assert_eq!("1000\n2000\n\n3000\n", client.input(1).unwrap());
+        assert_eq!("1000\n2000\n\n3000\n", client.input(1).unwrap());
+
+        let requests = server.join().unwrap();
+        assert_eq!(1, requests.len());
+        assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=53616c7465\r\n"));
+
+        let (base_url, server) = serve(vec![(
+            404,
+            "Please don't repeatedly request this endpoint before it unlocks!\n",
+        )]);
+        let err = stand_in_client(base_url, "locked").input(25).unwrap_err();
+        server.join().unwrap();
+        assert!(
+            matches!(err, ClientError::Status(404, ref body) if body.starts_with("Please don't"))
+        );
+
+        let mut client = stand_in_client(String::from("http://127.0.0.1:1"), "session");
+        client.config.session = None;
+        assert!(matches!(client.input(1), Err(ClientError::MissingSession)));
+    }
+
+    #[test]
+    fn submissions_respect_the_wait() {
+        let (base_url, server) = serve(vec![
+            (200, "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>"),
+            (200, "<article><p>That's not the right answer; your answer is too high. Please wait one minute before trying again.</p></article>"),
+        ]);
+        let client = stand_in_client(base_url, "submit");
+        assert_eq!(
+            Verdict::Correct,
+            client.submit(1, Part::A, "72511").unwrap()
+        );
+        assert_eq!(
+            Verdict::Incorrect {
+                hint: Some(String::from("too high")),
+                wait: Some(Duration::from_secs(60))
+            },
+            client.submit(1, Part::B, "NL CD").unwrap()
+        );
+        // the stand-in only answers twice, so this one must not reach it
+        assert!(matches!(
+            client.submit(1, Part::B, "1"),
+            Ok(Verdict::TooSoon(_))
+        ));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2022/day/1/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=1&answer=72511"));
+        assert!(requests[1].ends_with("level=2&answer=NL%20CD"));
+
+        assert_eq!(
+            Verdict::TooSoon(Duration::from_secs(63)),
+            Verdict::parse("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 3s left to wait. <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article>")
+        );
+        assert_eq!(
+            Verdict::AlreadySolved,
+            Verdict::parse("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>")
+        );
+    }
+}
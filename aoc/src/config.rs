@@ -0,0 +1,103 @@
+use std::{env, path::PathBuf};
+
+use aoc_common::{
+    input,
+    parse::{lines, ParseError},
+};
+
+use crate::puzzles::workspace;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_YEAR: u16 = 2022;
+pub const DEFAULT_USER_AGENT: &str = "aoc-2022-runner (std-only Rust client)";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    pub year: u16,
+    pub user_agent: String,
+    pub cache_dir: PathBuf,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            session: None,
+            base_url: String::from(DEFAULT_BASE_URL),
+            year: DEFAULT_YEAR,
+            user_agent: String::from(DEFAULT_USER_AGENT),
+            cache_dir: workspace().join(".cache"),
+        }
+    }
+}
+
+impl Config {
+    // `key = value` lines, with `#` starting a comment line
+    pub fn parse(input: &str) -> Result<Config, ParseError> {
+        let mut config = Config::default();
+        for line in lines(input) {
+            let text = line.text.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+            let (key, value) = text
+                .split_once('=')
+                .ok_or_else(|| line.error("expected `key = value`"))?;
+            let value = value.trim().to_string();
+            match key.trim() {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value.trim_end_matches('/').to_string(),
+                "year" => {
+                    config.year = value
+                        .parse()
+                        .map_err(|err| line.error(format!("invalid year: {}", err)))?
+                }
+                "user_agent" => config.user_agent = value,
+                "cache_dir" => config.cache_dir = workspace().join(value),
+                key => return Err(line.error(format!("unknown setting {:?}", key))),
+            }
+        }
+        Ok(config)
+    }
+
+    // aoc.conf in the workspace, or the file named by AOC_CONFIG; AOC_SESSION overrides the session
+    pub fn load() -> Result<Config, String> {
+        let path = env::var_os("AOC_CONFIG")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| workspace().join("aoc.conf"));
+        let mut config = if path.exists() {
+            let text = input::read_file(&path).map_err(|err| err.to_string())?;
+            Config::parse(&text).map_err(|err| format!("{}: {}", path.display(), err))?
+        } else {
+            Config::default()
+        };
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settings() {
+        let config = Config::parse(
+            "# local stand-in\nsession = 53616c7465\nbase_url = http://127.0.0.1:8080/\nyear = 2021\n",
+        )
+        .unwrap();
+        assert_eq!(Some("53616c7465"), config.session.as_deref());
+        assert_eq!("http://127.0.0.1:8080", config.base_url);
+        assert_eq!(2021, config.year);
+        assert_eq!(DEFAULT_USER_AGENT, config.user_agent);
+
+        let err = Config::parse("session = x\ntoken = y\n").unwrap_err();
+        assert_eq!(
+            "line 2: unknown setting \"token\" in \"token = y\"",
+            err.to_string()
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use crate::puzzles::Part;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answer: Option<String>,
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for ch in html.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+    decode_entities(&text)
+}
+
+fn first_element<'a>(html: &'a str, open: &str, close: &str) -> Option<&'a str> {
+    Some(html.split_once(open)?.1.split_once(close)?.0)
+}
+
+// the puzzle descriptions, one <article class="day-desc"> per part
+fn articles(html: &str) -> Vec<&str> {
+    html.split("<article")
+        .skip(1)
+        .filter(|rest| rest.starts_with(" class=\"day-desc\""))
+        .filter_map(|rest| rest.split_once('>')?.1.split("</article>").next())
+        .collect()
+}
+
+// the example is the first <pre><code> block of the part's description, part two
+// usually reuses the one from part one; the answer is the last emphasised code
+pub fn extract(html: &str, part: Part) -> Result<Example, String> {
+    let articles = articles(html);
+    let index = match part {
+        Part::A => 0,
+        Part::B => 1,
+    };
+    let article = articles.get(index).ok_or_else(|| match part {
+        Part::A => String::from("No puzzle description found"),
+        Part::B => String::from(
+            "No part two description found, was the page saved after solving part one?",
+        ),
+    })?;
+
+    let input = articles[..=index]
+        .iter()
+        .rev()
+        .find_map(|article| first_element(article, "<pre><code>", "</code></pre>"))
+        .map(strip_tags)
+        .ok_or("No <pre><code> example found")?;
+
+    let answer = [
+        ("<code><em>", "</em></code>"),
+        ("<em><code>", "</code></em>"),
+    ]
+    .into_iter()
+    .flat_map(|(open, close)| {
+        article
+            .match_indices(open)
+            .filter_map(move |(i, _)| Some((i, article[i + open.len()..].split_once(close)?.0)))
+    })
+    .max_by_key(|(i, _)| *i)
+    .map(|(_, answer)| strip_tags(answer));
+
+    Ok(Example { input, answer })
+}
+
+// an example test going through the day's `Solution` with the default config,
+// comparing the answer as text so it fits every part's answer and error types;
+// without a known answer the test is ignored until one is filled in
+pub fn test_stub(day: u8, part: Part, answer: Option<&str>) -> String {
+    let function = match part {
+        Part::A => "part1",
+        Part::B => "part2",
+    };
+    let ignore = match answer {
+        Some(_) => "",
+        None => "    #[ignore = \"fill in the example's answer\"]\n",
+    };
+    format!(
+        "    #[test]
+{ignore}    fn example_{function}() {{
+        let input = Day{day:02}::parse(include_str!(\"example.txt\")).unwrap();
+        let answer = Day{day:02}::{function}(&input, &Default::default())
+            .map(|answer| answer.to_string())
+            .map_err(|err| err.to_string());
+        assert_eq!(Ok(String::from({expected:?})), answer);
+    }}
+",
+        expected = answer.unwrap_or_default(),
+    )
+}
+
+pub fn test_module(stub: &str) -> String {
+    format!(
+        "#[cfg(test)]
+mod tests {{
+    use super::*;
+
+{}}}
+",
+        stub
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 5: Supply Stacks ---</h2><p>For example:</p>
+<pre><code>    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+</code></pre>
+<p>After the rearrangement procedure completes, the crates on top are <code>C</code>, <code>M</code> and <code>Z</code>, so you should give the Elves <code><em>CMZ</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>VQZNJMWTR</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>In this example, the CrateMover 9001 puts crates on top of each other in a <em>different order</em>; it ends with <em><code>MCD</code></em> &amp; not <code>CMZ</code>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn examples_and_answers() {
+        let example = extract(PAGE, Part::A).unwrap();
+        assert!(example.input.starts_with("    [D]\n[N] [C]"));
+        assert!(example.input.ends_with("move 1 from 2 to 1\n"));
+        assert_eq!(Some("CMZ"), example.answer.as_deref());
+
+        let example = extract(PAGE, Part::B).unwrap();
+        assert!(example.input.starts_with("    [D]"));
+        assert_eq!(Some("MCD"), example.answer.as_deref());
+
+        let part_one_only = &PAGE[..PAGE.find("<p>Your").unwrap()];
+        assert!(extract(part_one_only, Part::B).is_err());
+    }
+
+    #[test]
+    fn stubs() {
+        let stub = test_stub(1, Part::A, Some("24000"));
+        assert!(stub.contains("fn example_part1()"));
+        assert!(stub.contains("let input = Day01::parse(include_str!(\"example.txt\")).unwrap();"));
+        assert!(stub.contains("Day01::part1(&input, &Default::default())"));
+        assert!(stub.contains("assert_eq!(Ok(String::from(\"24000\")), answer);"));
+        assert!(!stub.contains("#[ignore"));
+        let stub = test_stub(5, Part::B, Some("MCD"));
+        assert!(stub.contains("Day05::part2(&input, &Default::default())"));
+        assert!(stub.contains("assert_eq!(Ok(String::from(\"MCD\")), answer);"));
+        assert!(test_module(&stub)
+            .starts_with("#[cfg(test)]\nmod tests {\n    use super::*;\n\n    #[test]"));
+        let stub = test_stub(16, Part::B, None);
+        assert!(stub.starts_with("    #[test]\n    #[ignore = \"fill in the example's answer\"]\n"));
+        assert!(stub.contains("assert_eq!(Ok(String::from(\"\")), answer);"));
+    }
+}
@@ -0,0 +1,229 @@
+use std::{
+    fmt::Display,
+    io::{self, Read, Write},
+    net::TcpStream,
+    process::{Command, Stdio},
+    time::Duration,
+};
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+}
+
+impl Display for Method {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Method::Get => f.pad("GET"),
+            Method::Post => f.pad("POST"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+#[derive(Debug)]
+pub enum HttpError {
+    InvalidUrl(String),
+    Io(io::Error),
+    InvalidResponse(String),
+    Curl(String),
+}
+
+impl Display for HttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidUrl(url) => write!(f, "Invalid URL: {}", url),
+            Self::Io(err) => write!(f, "{}", err),
+            Self::InvalidResponse(reason) => write!(f, "Invalid response: {}", reason),
+            Self::Curl(err) => write!(f, "curl failed: {}", err),
+        }
+    }
+}
+
+impl From<io::Error> for HttpError {
+    fn from(err: io::Error) -> Self {
+        HttpError::Io(err)
+    }
+}
+
+// std has no TLS, so plain http is spoken directly (a local stand-in server)
+// and https is handed to the system's curl
+pub fn send(request: &Request) -> Result<Response, HttpError> {
+    if let Some(rest) = request.url.strip_prefix("http://") {
+        send_plain(request, rest)
+    } else if request.url.starts_with("https://") {
+        send_curl(request)
+    } else {
+        Err(HttpError::InvalidUrl(request.url.clone()))
+    }
+}
+
+fn send_plain(request: &Request, url: &str) -> Result<Response, HttpError> {
+    let (authority, path) = match url.find('/') {
+        Some(i) => url.split_at(i),
+        None => (url, "/"),
+    };
+    if authority.is_empty() {
+        return Err(HttpError::InvalidUrl(request.url.clone()));
+    }
+    let address = if authority.contains(':') {
+        authority.to_string()
+    } else {
+        format!("{}:80", authority)
+    };
+
+    let mut message = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n",
+        request.method, path, authority
+    );
+    for (name, value) in &request.headers {
+        message += &format!("{}: {}\r\n", name, value);
+    }
+    if let Some(body) = &request.body {
+        message += &format!("Content-Length: {}\r\n\r\n{}", body.len(), body);
+    } else {
+        message += "\r\n";
+    }
+
+    let mut stream = TcpStream::connect(address)?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+    stream.write_all(message.as_bytes())?;
+    let mut raw = vec![];
+    stream.read_to_end(&mut raw)?;
+    parse_response(&raw)
+}
+
+fn parse_response(raw: &[u8]) -> Result<Response, HttpError> {
+    let invalid = |reason: &str| HttpError::InvalidResponse(reason.to_string());
+    let text = String::from_utf8_lossy(raw);
+    let (head, body) = text
+        .split_once("\r\n\r\n")
+        .ok_or_else(|| invalid("no end of headers"))?;
+    let mut lines = head.lines();
+    let status = lines
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|status| status.parse().ok())
+        .ok_or_else(|| invalid("no status"))?;
+    let chunked = lines.any(|line| {
+        line.split_once(':').is_some_and(|(name, value)| {
+            name.eq_ignore_ascii_case("transfer-encoding") && value.trim() == "chunked"
+        })
+    });
+    let body = if chunked {
+        decode_chunked(body).ok_or_else(|| invalid("bad chunked body"))?
+    } else {
+        body.to_string()
+    };
+    Ok(Response { status, body })
+}
+
+fn decode_chunked(mut body: &str) -> Option<String> {
+    let mut out = String::new();
+    loop {
+        let (size, rest) = body.split_once("\r\n")?;
+        let size = usize::from_str_radix(size.split(';').next()?.trim(), 16).ok()?;
+        if size == 0 {
+            return Some(out);
+        }
+        out += rest.get(..size)?;
+        body = rest.get(size..)?.strip_prefix("\r\n")?;
+    }
+}
+
+// everything goes through curl's stdin so the session cookie stays out of the process list
+fn send_curl(request: &Request) -> Result<Response, HttpError> {
+    let quote = |s: &str| format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""));
+    let mut config = format!(
+        "silent\nshow-error\nrequest = {}\nurl = {}\nwrite-out = \"\\n%{{http_code}}\"\nmax-time = {}\n",
+        request.method,
+        quote(&request.url),
+        TIMEOUT.as_secs()
+    );
+    for (name, value) in &request.headers {
+        config += &format!("header = {}\n", quote(&format!("{}: {}", name, value)));
+    }
+    if let Some(body) = &request.body {
+        config += &format!("data-binary = {}\n", quote(body));
+    }
+
+    let mut child = Command::new("curl")
+        .args(["--config", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| HttpError::Curl(err.to_string()))?;
+    child
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(config.as_bytes())?;
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(HttpError::Curl(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (body, status) = stdout
+        .rsplit_once('\n')
+        .ok_or_else(|| HttpError::InvalidResponse(String::from("no status from curl")))?;
+    let status = status
+        .trim()
+        .parse()
+        .map_err(|_| HttpError::InvalidResponse(format!("status {:?}", status)))?;
+    Ok(Response {
+        status,
+        body: body.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_and_chunked_responses() {
+        let response =
+            parse_response(b"HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\n1\n2\n3").unwrap();
+        assert_eq!(200, response.status);
+        assert_eq!("1\n2\n3", response.body);
+
+        let chunked = b"HTTP/1.1 404 Not Found\r\nTransfer-Encoding: chunked\r\n\r\n\
+            4\r\nNot \r\n5\r\nFound\r\n0\r\n\r\n";
+        let response = parse_response(chunked).unwrap();
+        assert_eq!(
+            (404, "Not Found"),
+            (response.status, response.body.as_str())
+        );
+
+        assert!(matches!(
+            send(&Request {
+                method: Method::Get,
+                url: String::from("ftp://example.com/"),
+                headers: vec![],
+                body: None,
+            }),
+            Err(HttpError::InvalidUrl(_))
+        ));
+    }
+}
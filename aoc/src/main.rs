@@ -0,0 +1,469 @@
+use std::{
+    env, fs,
+    path::PathBuf,
+    process,
+    time::{Duration, Instant},
+};
+
+use answers::{input_hash, Answers, Outcome};
+use aoc_common::{input, parallel, render, OrExit};
+use args::{parse_args, Command, Selection, USAGE};
+use bench::{Phase, Record, Stats};
+use client::{Client, Verdict};
+use config::Config;
+use puzzles::{Part, Puzzle, PUZZLES};
+
+mod answers;
+mod args;
+mod bench;
+mod client;
+mod config;
+mod example;
+mod http;
+mod puzzles;
+
+fn print_row(day: u8, part: puzzles::Part, time: &str, answer: &str) {
+    let mut lines = answer.lines();
+    println!(
+        "{:>3}  {:<4}  {:>10}  {}",
+        day,
+        part,
+        time,
+        lines.next().unwrap_or_default()
+    );
+    for line in lines {
+        println!("{:23}{}", "", line);
+    }
+}
+
+fn client() -> Client {
+    Client::new(Config::load().or_exit())
+}
+
+// downloads the puzzle's input into its src/input.txt
+fn fill_input(client: &Client, puzzle: &Puzzle) -> Result<String, String> {
+    let input = client.input(puzzle.day).map_err(|err| err.to_string())?;
+    let path = puzzle.input_path();
+    fs::write(&path, &input).map_err(|err| format!("Cannot write {}: {}", path.display(), err))?;
+    Ok(input)
+}
+
+// the puzzles in the selection with their input, or the error reading it
+fn selected_puzzles<'a>(
+    selection: &'a Selection,
+    input: Option<&str>,
+    client: Option<&'a Client>,
+) -> impl Iterator<Item = (&'static Puzzle, Result<String, String>)> + 'a {
+    let shared_input = input.map(input::read).transpose().or_exit();
+    PUZZLES
+        .iter()
+        .filter(|puzzle| selection.contains(puzzle.day, puzzle.part))
+        .map(move |puzzle| {
+            let input = match (&shared_input, client) {
+                (Some(input), _) => Ok(input.clone()),
+                (None, Some(client)) if !puzzle.input_path().exists() => fill_input(client, puzzle),
+                (None, _) => input::read_file(puzzle.input_path()).map_err(|err| err.to_string()),
+            };
+            (puzzle, input)
+        })
+}
+
+fn run(selection: &Selection, input: Option<&str>, fetch: bool) {
+    let client = fetch.then(client);
+    let puzzles = selected_puzzles(selection, input, client.as_ref());
+
+    println!("Day  Part        Time  Answer");
+    println!("---  ----  ----------  ------");
+
+    let mut rendered = vec![];
+    for (puzzle, input) in puzzles {
+        let input = match input {
+            Ok(input) => input,
+            Err(err) => {
+                print_row(puzzle.day, puzzle.part, "-", &format!("error: {}", err));
+                continue;
+            }
+        };
+
+        render::start(&format!("day{:02}{}", puzzle.day, puzzle.part));
+        let start = Instant::now();
+        let answer = (puzzle.solve)(&input).unwrap_or_else(|message| message);
+        let elapsed = start.elapsed();
+
+        print_row(puzzle.day, puzzle.part, &format_time(elapsed), &answer);
+        match render::finish() {
+            Ok(files) => rendered.extend(files),
+            Err(err) => eprintln!("error: {}", err),
+        }
+    }
+
+    for file in rendered {
+        println!("Wrote {}", file.display());
+    }
+}
+
+fn format_time(time: Duration) -> String {
+    format!("{:.3} ms", time.as_secs_f64() * 1000.0)
+}
+
+fn bench(selection: &Selection, input: Option<&str>, iterations: usize, output: &str) {
+    let puzzles = selected_puzzles(selection, input, None);
+
+    println!("Day  Part  Phase           Min        Median           Max");
+    println!("---  ----  -----  ------------  ------------  ------------");
+
+    let mut records = vec![];
+    for (puzzle, input) in puzzles {
+        let input = match input {
+            Ok(input) => input,
+            Err(err) => {
+                println!("{:>3}  {:<4}  error: {}", puzzle.day, puzzle.part, err);
+                continue;
+            }
+        };
+
+        let samples = match (0..iterations)
+            .map(|_| (puzzle.bench)(&input))
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(samples) => samples,
+            Err(message) => {
+                println!("{:>3}  {:<4}  {}", puzzle.day, puzzle.part, message);
+                continue;
+            }
+        };
+        for phase in [Phase::Parse, Phase::Solve] {
+            let times = samples
+                .iter()
+                .map(|sample| match phase {
+                    Phase::Parse => sample.parse,
+                    Phase::Solve => sample.solve,
+                })
+                .collect();
+            let stats = Stats::new(times);
+            println!(
+                "{:>3}  {:<4}  {:<5}  {:>12}  {:>12}  {:>12}",
+                puzzle.day,
+                puzzle.part,
+                phase,
+                format_time(stats.min),
+                format_time(stats.median),
+                format_time(stats.max)
+            );
+            records.push(Record {
+                day: puzzle.day,
+                part: puzzle.part,
+                phase,
+                iterations,
+                stats,
+            });
+        }
+    }
+
+    if let Err(err) = fs::write(output, bench::format_results(&records)) {
+        eprintln!("error: Cannot write {}: {}", output, err);
+        process::exit(1);
+    }
+    println!("\nResults written to {}", output);
+}
+
+fn read_results(path: &str) -> Vec<Record> {
+    let text = input::read_file(path).or_exit();
+    bench::parse_results(&text)
+        .map_err(|err| format!("{}: {}", path, err))
+        .or_exit()
+}
+
+fn compare(baseline: &str, current: &str, threshold: f64) {
+    let comparisons = bench::compare(&read_results(baseline), &read_results(current));
+
+    println!("Day  Part  Phase      Baseline       Current    Change");
+    println!("---  ----  -----  ------------  ------------  --------");
+
+    let mut regressions = 0;
+    for comparison in &comparisons {
+        let regression = comparison.is_regression(threshold);
+        if regression {
+            regressions += 1;
+        }
+        println!(
+            "{:>3}  {:<4}  {:<5}  {:>12}  {:>12}  {:>+7.1}%{}",
+            comparison.day,
+            comparison.part,
+            comparison.phase,
+            format_time(comparison.baseline),
+            format_time(comparison.current),
+            comparison.change(),
+            if regression { "  regression" } else { "" }
+        );
+    }
+
+    if regressions > 0 {
+        println!(
+            "\n{} regression(s) above the {}% threshold",
+            regressions, threshold
+        );
+        process::exit(1);
+    }
+}
+
+fn load_answers(path: &PathBuf) -> Answers {
+    if !path.exists() {
+        return Answers::default();
+    }
+    let text = input::read_file(path).or_exit();
+    Answers::parse(&text)
+        .map_err(|err| format!("{}: {}", path.display(), err))
+        .or_exit()
+}
+
+fn verify(selection: &Selection, answers_path: Option<&str>, record: bool) {
+    let path = answers_path
+        .map(PathBuf::from)
+        .unwrap_or_else(|| puzzles::workspace().join("answers.tsv"));
+    let mut answers = load_answers(&path);
+
+    println!("Day  Part  Input                     Result    Answer");
+    println!("---  ----  ------------------------  --------  ------");
+
+    let (mut passed, mut failed, mut unknown, mut recorded) = (0, 0, 0, 0);
+    for puzzle in PUZZLES
+        .iter()
+        .filter(|puzzle| selection.contains(puzzle.day, puzzle.part))
+    {
+        for input_path in puzzle.registered_inputs() {
+            let name = input_path
+                .strip_prefix(puzzles::workspace())
+                .unwrap_or(&input_path)
+                .display()
+                .to_string();
+            let input = match input::read_file(&input_path) {
+                Ok(input) => input,
+                Err(err) => {
+                    println!(
+                        "{:>3}  {:<4}  {:<24}  error: {}",
+                        puzzle.day, puzzle.part, name, err
+                    );
+                    failed += 1;
+                    continue;
+                }
+            };
+
+            let (outcome, answer) = puzzle.check(&input, &answers);
+
+            let mut result = outcome.to_string();
+            match (&outcome, &answer) {
+                (Outcome::Unknown, Ok(answer)) if record => {
+                    answers.insert(&input_hash(&input), puzzle.day, puzzle.part, answer);
+                    result = String::from("recorded");
+                    recorded += 1;
+                }
+                (Outcome::Pass, _) => passed += 1,
+                (Outcome::Fail(_), _) => failed += 1,
+                (Outcome::Unknown, _) => unknown += 1,
+            }
+
+            let answer = answer.unwrap_or_else(|message| message);
+            let mut lines = answer.lines();
+            println!(
+                "{:>3}  {:<4}  {:<24}  {:<8}  {}",
+                puzzle.day,
+                puzzle.part,
+                name,
+                result,
+                lines.next().unwrap_or_default()
+            );
+            for line in lines {
+                println!("{:49}{}", "", line);
+            }
+            if let Outcome::Fail(expected) = outcome {
+                for (i, line) in expected.lines().enumerate() {
+                    let label = if i == 0 { "expected:" } else { "" };
+                    println!("{:39}{:<10}{}", "", label, line);
+                }
+            }
+        }
+    }
+
+    if recorded > 0 {
+        if let Err(err) = fs::write(&path, answers.to_string()) {
+            eprintln!("error: Cannot write {}: {}", path.display(), err);
+            process::exit(1);
+        }
+    }
+
+    println!(
+        "\n{} passed, {} failed, {} unknown, {} recorded",
+        passed, failed, unknown, recorded
+    );
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+fn fetch(selection: &Selection) {
+    let client = client();
+
+    println!("Day  Part  Input                 Status");
+    println!("---  ----  --------------------  ------");
+
+    let mut failed = false;
+    for puzzle in PUZZLES
+        .iter()
+        .filter(|puzzle| selection.contains(puzzle.day, puzzle.part))
+    {
+        let status = if puzzle.input_path().exists() {
+            String::from("present")
+        } else {
+            match fill_input(&client, puzzle) {
+                Ok(_) => String::from("fetched"),
+                Err(err) => {
+                    failed = true;
+                    format!("error: {}", err)
+                }
+            }
+        };
+        println!(
+            "{:>3}  {:<4}  {:<20}  {}",
+            puzzle.day,
+            puzzle.part,
+            format!("{}/src/input.txt", puzzle.name),
+            status
+        );
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn submit(day: u8, part: Part, answer: Option<&str>) {
+    let client = client();
+    let answer = match answer {
+        Some(answer) => answer.to_string(),
+        None => {
+            let puzzle = PUZZLES
+                .iter()
+                .find(|puzzle| puzzle.day == day && puzzle.part == part)
+                .ok_or_else(|| format!("There is no solver for day {} part {}", day, part))
+                .or_exit();
+            let input = match puzzle.input_path().exists() {
+                true => input::read_file(puzzle.input_path()).map_err(|err| err.to_string()),
+                false => fill_input(&client, puzzle),
+            }
+            .or_exit();
+            (puzzle.solve)(&input).or_exit()
+        }
+    };
+
+    println!("Submitting {} for day {} part {}", answer, day, part);
+    let verdict = client.submit(day, part, &answer).or_exit();
+    println!("{}", verdict);
+    if verdict != Verdict::Correct {
+        process::exit(1);
+    }
+}
+
+fn example(day: u8, part: Part, html: &str, force: bool) {
+    let puzzle = PUZZLES
+        .iter()
+        .find(|puzzle| puzzle.day == day && puzzle.part == part)
+        .ok_or_else(|| format!("There is no crate for day {} part {}", day, part))
+        .or_exit();
+    let html = input::read_file(html).or_exit();
+    let example = example::extract(&html, part).or_exit();
+
+    // both parts share the crate's example.txt, part two usually reuses the example
+    let path = puzzle.input_path().with_file_name("example.txt");
+    let existing = fs::read_to_string(&path).ok();
+    if existing.as_deref() == Some(example.input.as_str()) {
+        println!("{} already has this example", path.display());
+    } else if existing.is_some() && !force {
+        eprintln!(
+            "error: {} already exists, use --force to overwrite it",
+            path.display()
+        );
+        process::exit(1);
+    } else {
+        fs::write(&path, &example.input)
+            .map_err(|err| format!("Cannot write {}: {}", path.display(), err))
+            .or_exit();
+        println!("Wrote {}", path.display());
+    }
+
+    let stub = example::test_stub(day, part, example.answer.as_deref());
+    let lib = puzzle.input_path().with_file_name("lib.rs");
+    let source = input::read_file(&lib).or_exit();
+    if source.contains("#[cfg(test)]") {
+        println!(
+            "{} already has tests, add the example to them:\n\n{}",
+            lib.display(),
+            stub
+        );
+    } else {
+        fs::write(&lib, format!("{}\n{}", source, example::test_module(&stub)))
+            .map_err(|err| format!("Cannot write {}: {}", lib.display(), err))
+            .or_exit();
+        println!("Added an example test to {}", lib.display());
+    }
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    match parse_args(&args) {
+        Ok(Command::Run {
+            selection,
+            input,
+            fetch,
+            visualize,
+            render,
+            threads,
+        }) => {
+            if let Some(threads) = threads {
+                parallel::set_threads(threads);
+            }
+            if let Some(settings) = visualize {
+                aoc_common::visualize::enable(settings);
+            }
+            if let Some(dir) = render {
+                render::enable(dir).or_exit();
+            }
+            run(&selection, input.as_deref(), fetch)
+        }
+        Ok(Command::Bench {
+            selection,
+            input,
+            iterations,
+            output,
+            threads,
+        }) => {
+            if let Some(threads) = threads {
+                parallel::set_threads(threads);
+            }
+            bench(&selection, input.as_deref(), iterations, &output)
+        }
+        Ok(Command::Compare {
+            baseline,
+            current,
+            threshold,
+        }) => compare(&baseline, &current, threshold),
+        Ok(Command::Verify {
+            selection,
+            answers,
+            record,
+        }) => verify(&selection, answers.as_deref(), record),
+        Ok(Command::Fetch { selection }) => fetch(&selection),
+        Ok(Command::Submit { day, part, answer }) => submit(day, part, answer.as_deref()),
+        Ok(Command::Example {
+            day,
+            part,
+            html,
+            force,
+        }) => example(day, part, &html, force),
+        Ok(Command::Help) => println!("{}", USAGE),
+        Err(err) => {
+            eprintln!("{}\n\n{}", err, USAGE);
+            process::exit(2);
+        }
+    }
+}
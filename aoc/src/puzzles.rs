@@ -0,0 +1,270 @@
+use std::{
+    any::Any,
+    fmt::Display,
+    fs,
+    hint::black_box,
+    panic,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Instant,
+};
+
+use aoc_common::Solution;
+
+use crate::{
+    answers::{input_hash, Answers, Outcome},
+    bench::Sample,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::A => f.pad("a"),
+            Part::B => f.pad("b"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" => Ok(Part::A),
+            "b" => Ok(Part::B),
+            _ => Err(format!("unknown part {:?}", s)),
+        }
+    }
+}
+
+pub struct Puzzle {
+    pub day: u8,
+    pub part: Part,
+    pub name: &'static str,
+    // the answer, or the message to show instead when there is none
+    pub solve: fn(&str) -> Result<String, String>,
+    // the times of one run, or the message to show when the input does not parse
+    pub bench: fn(&str) -> Result<Sample, String>,
+}
+
+pub fn workspace() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+impl Puzzle {
+    pub fn input_path(&self) -> PathBuf {
+        workspace().join(self.name).join("src").join("input.txt")
+    }
+
+    // the bundled input followed by everyone else's, saved as inputs/<name>/dayNN.txt
+    pub fn registered_inputs(&self) -> Vec<PathBuf> {
+        let file_name = format!("day{:02}.txt", self.day);
+        let mut others = fs::read_dir(workspace().join("inputs"))
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| Some(entry.ok()?.path().join(&file_name)))
+            .filter(|path| path.is_file())
+            .collect::<Vec<_>>();
+        others.sort();
+        [self.input_path()]
+            .into_iter()
+            .filter(|path| path.is_file())
+            .chain(others)
+            .collect()
+    }
+
+    // the answer for an input against the known one; a solver that panics fails
+    // whether or not the answer is known
+    pub fn check(&self, input: &str, answers: &Answers) -> (Outcome, Result<String, String>) {
+        let hash = input_hash(input);
+        let expected = answers.get(&hash, self.day, self.part);
+        match panic::catch_unwind(|| (self.solve)(input)) {
+            Ok(Ok(answer)) => (
+                answers.check(&hash, self.day, self.part, &answer),
+                Ok(answer),
+            ),
+            Ok(Err(message)) => match expected {
+                Some(expected) => (Outcome::Fail(expected.to_string()), Err(message)),
+                None => (Outcome::Unknown, Err(message)),
+            },
+            Err(payload) => (
+                Outcome::Fail(expected.unwrap_or_default().to_string()),
+                Err(format!("panicked: {}", panic_message(&*payload))),
+            ),
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message,
+        None => payload.downcast_ref::<String>().map_or("", String::as_str),
+    }
+}
+
+fn answer<T: Display, E: Display>(result: Result<T, E>) -> Result<String, String> {
+    result
+        .map(|answer| answer.to_string())
+        .map_err(|err| format!("error: {}", err))
+}
+
+fn solve<S: Solution>(part: Part, input: &str) -> Result<String, String> {
+    let input = S::parse(input).map_err(|err| format!("error: {}", err))?;
+    let config = S::Config::default();
+    match part {
+        Part::A => answer(S::part1(&input, &config)),
+        Part::B => answer(S::part2(&input, &config)),
+    }
+}
+
+fn bench<S: Solution>(part: Part, input: &str) -> Result<Sample, String> {
+    let config = S::Config::default();
+    let start = Instant::now();
+    let parsed = black_box(S::parse(black_box(input)));
+    let parse = start.elapsed();
+    let parsed = parsed.map_err(|err| format!("error: {}", err))?;
+    // the answers are only dropped once the clock has stopped
+    let (mut part1, mut part2) = (None, None);
+    let start = Instant::now();
+    match part {
+        Part::A => part1 = Some(black_box(S::part1(&parsed, &config))),
+        Part::B => part2 = Some(black_box(S::part2(&parsed, &config))),
+    }
+    let solve = start.elapsed();
+    drop((part1, part2));
+    Ok(Sample { parse, solve })
+}
+
+macro_rules! puzzle {
+    ($day:literal, $part:ident, $krate:ident, $solution:ty) => {
+        Puzzle {
+            day: $day,
+            part: Part::$part,
+            name: stringify!($krate),
+            solve: |input| solve::<$solution>(Part::$part, input),
+            bench: |input| bench::<$solution>(Part::$part, input),
+        }
+    };
+}
+
+pub const PUZZLES: &[Puzzle] = &[
+    puzzle!(1, A, day01, day01::Day01),
+    puzzle!(1, B, day01, day01::Day01),
+    puzzle!(2, A, day02, day02::Day02),
+    puzzle!(2, B, day02, day02::Day02),
+    puzzle!(3, A, day03, day03::Day03),
+    puzzle!(3, B, day03, day03::Day03),
+    puzzle!(4, A, day04, day04::Day04),
+    puzzle!(4, B, day04, day04::Day04),
+    puzzle!(5, A, day05, day05::Day05),
+    puzzle!(5, B, day05, day05::Day05),
+    puzzle!(6, A, day06, day06::Day06),
+    puzzle!(6, B, day06, day06::Day06),
+    puzzle!(7, A, day07, day07::Day07),
+    puzzle!(7, B, day07, day07::Day07),
+    puzzle!(8, A, day08, day08::Day08),
+    puzzle!(8, B, day08, day08::Day08),
+    puzzle!(9, A, day09, day09::Day09),
+    puzzle!(9, B, day09, day09::Day09),
+    puzzle!(10, A, day10, day10::Day10),
+    puzzle!(10, B, day10, day10::Day10),
+    puzzle!(11, A, day11, day11::Day11),
+    puzzle!(11, B, day11, day11::Day11),
+    puzzle!(12, A, day12, day12::Day12),
+    puzzle!(12, B, day12, day12::Day12),
+    puzzle!(13, A, day13, day13::Day13),
+    puzzle!(13, B, day13, day13::Day13),
+    puzzle!(14, A, day14, day14::Day14),
+    puzzle!(14, B, day14, day14::Day14),
+    puzzle!(15, A, day15, day15::Day15),
+    puzzle!(15, B, day15, day15::Day15),
+    puzzle!(16, A, day16, day16::Day16),
+    puzzle!(16, B, day16, day16::Day16),
+    puzzle!(17, A, day17, day17::Day17),
+    puzzle!(17, B, day17, day17::Day17),
+    puzzle!(18, A, day18, day18::Day18),
+    puzzle!(18, B, day18, day18::Day18),
+    puzzle!(19, A, day19, day19::Day19),
+    puzzle!(19, B, day19, day19::Day19),
+    puzzle!(20, A, day20, day20::Day20),
+    puzzle!(20, B, day20, day20::Day20),
+    puzzle!(21, A, day21, day21::Day21),
+    puzzle!(21, B, day21, day21::Day21),
+    puzzle!(22, A, day22, day22::Day22),
+    puzzle!(22, B, day22, day22::Day22),
+    puzzle!(23, A, day23, day23::Day23),
+    puzzle!(23, B, day23, day23::Day23),
+    puzzle!(24, A, day24, day24::Day24),
+    puzzle!(24, B, day24, day24::Day24),
+    puzzle!(25, A, day25, day25::Day25),
+];
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::parallel;
+
+    use super::*;
+
+    // the bundled inputs against answers.tsv; inputs nobody has recorded answers
+    // for are skipped, so everyone's checkout passes with their own input
+    #[test]
+    fn known_answers() {
+        let answers = fs::read_to_string(workspace().join("answers.tsv")).unwrap_or_default();
+        let answers = Answers::parse(&answers).unwrap();
+        let failures = parallel::map(PUZZLES, |puzzle| {
+            let input = fs::read_to_string(puzzle.input_path()).ok()?;
+            match puzzle.check(&input, &answers) {
+                (Outcome::Fail(expected), answer) => Some(format!(
+                    "day {} part {}: expected {:?}, got {:?}",
+                    puzzle.day, puzzle.part, expected, answer
+                )),
+                _ => None,
+            }
+        });
+        let failures = failures.into_iter().flatten().collect::<Vec<_>>();
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn panicking_solver() {
+        let puzzle = Puzzle {
+            day: 1,
+            part: Part::A,
+            name: "day01",
+            solve: |_| panic!("no elves"),
+            bench: |input| bench::<day01::Day01>(Part::A, input),
+        };
+        let mut answers = Answers::default();
+        assert_eq!(
+            (
+                Outcome::Fail(String::new()),
+                Err("panicked: no elves".to_string())
+            ),
+            puzzle.check("1000", &answers)
+        );
+        answers.insert(&input_hash("1000"), 1, Part::A, "1000");
+        assert_eq!(
+            (
+                Outcome::Fail("1000".to_string()),
+                Err("panicked: no elves".to_string())
+            ),
+            puzzle.check("1000", &answers)
+        );
+    }
+
+    #[test]
+    fn unparsable_bench_input() {
+        let puzzle = PUZZLES.iter().find(|puzzle| puzzle.day == 21).unwrap();
+        assert_eq!(
+            Err("error: line 1: expected \"<name>: <job>\" in \"root\"".to_string()),
+            (puzzle.bench)("root").map(|_| ())
+        );
+        assert!((puzzle.bench)("root: 5").is_ok());
+    }
+}
@@ -0,0 +1,62 @@
+use aoc_common::{
+    parse::{blocks, Block, ParseError},
+    NoAnswer, Solution,
+};
+
+fn total_calories(elf: Block) -> Result<i32, ParseError> {
+    elf.lines().map(|item| item.parse::<i32>()).sum()
+}
+
+pub fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    blocks(input).map(total_calories).collect()
+}
+
+pub fn part1(elf_calories: &[i32]) -> Option<i32> {
+    elf_calories.iter().copied().max()
+}
+
+pub fn part2(elf_calories: &[i32]) -> i32 {
+    let mut elf_calories = elf_calories.to_vec();
+    elf_calories.sort_unstable_by(|a, b| b.cmp(a));
+
+    elf_calories.iter().take(3).sum()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<i32>;
+    type Config = ();
+    type Part1 = i32;
+    type Part2 = i32;
+    type Error = NoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        part1(input).ok_or(NoAnswer)
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let elf_calories = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Some(24000), part1(&elf_calories));
+    }
+
+    #[test]
+    fn example_part2() {
+        let elf_calories = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(45000, part2(&elf_calories));
+    }
+}
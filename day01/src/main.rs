@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run::<day01::Day01>(include_str!("input.txt"));
+}
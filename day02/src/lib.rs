@@ -0,0 +1,101 @@
+use std::convert::Infallible;
+
+use aoc_common::{
+    parse::{parse_lines, ParseError},
+    Solution,
+};
+
+// both columns as 0, 1 or 2: rock, paper, scissors for the opponent, and either
+// our shape (part 1) or the outcome lose, draw, win (part 2) for the second one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    opponent: i32,
+    second: i32,
+}
+
+fn parse_round(line: &str) -> Result<Round, &'static str> {
+    match line.as_bytes() {
+        &[opponent @ b'A'..=b'C', b' ', second @ b'X'..=b'Z'] => Ok(Round {
+            opponent: (opponent - b'A') as i32,
+            second: (second - b'X') as i32,
+        }),
+        _ => Err("unexpected strategy"),
+    }
+}
+
+fn score(shape: i32, outcome: i32) -> i32 {
+    shape + 1 + outcome * 3
+}
+
+pub fn parse(input: &str) -> Result<Vec<Round>, ParseError> {
+    parse_lines(input, parse_round).collect()
+}
+
+pub fn part1(rounds: &[Round]) -> i32 {
+    rounds
+        .iter()
+        .map(|round| {
+            score(
+                round.second,
+                (round.second - round.opponent + 1).rem_euclid(3),
+            )
+        })
+        .sum()
+}
+
+pub fn part2(rounds: &[Round]) -> i32 {
+    rounds
+        .iter()
+        .map(|round| {
+            score(
+                (round.opponent + round.second - 1).rem_euclid(3),
+                round.second,
+            )
+        })
+        .sum()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<Round>;
+    type Config = ();
+    type Part1 = i32;
+    type Part2 = i32;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let rounds = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(15, part1(&rounds));
+    }
+
+    #[test]
+    fn example_part2() {
+        let rounds = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(12, part2(&rounds));
+    }
+
+    #[test]
+    fn malformed_input() {
+        let err = parse("A Y\nB Q\nC Z\n").unwrap_err();
+        assert_eq!("line 2: unexpected strategy in \"B Q\"", err.to_string());
+    }
+}
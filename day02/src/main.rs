@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run::<day02::Day02>(include_str!("input.txt"));
+}
@@ -0,0 +1,125 @@
+use aoc_common::{
+    parse::{lines, Line, ParseError},
+    NoAnswer, Solution,
+};
+
+// the item types of each compartment, as a set with bit n standing for priority n
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rucksack {
+    first: u64,
+    second: u64,
+}
+
+impl Rucksack {
+    fn items(&self) -> u64 {
+        self.first | self.second
+    }
+}
+
+fn get_priority(item_type: char) -> Result<u32, String> {
+    match item_type {
+        'a'..='z' => Ok(1 + (item_type as u32 - 'a' as u32)),
+        'A'..='Z' => Ok(27 + (item_type as u32 - 'A' as u32)),
+        _ => Err(format!("unexpected item type {:?}", item_type)),
+    }
+}
+
+fn item_set(items: &str) -> Result<u64, String> {
+    items
+        .chars()
+        .try_fold(0, |set, item| Ok(set | 1 << get_priority(item)?))
+}
+
+fn parse_rucksack(line: &str) -> Result<Rucksack, String> {
+    if !line.len().is_multiple_of(2) {
+        return Err(format!("odd number of items: {}", line.len()));
+    }
+    let (first, second) = line.split_at(line.len() / 2);
+    Ok(Rucksack {
+        first: item_set(first)?,
+        second: item_set(second)?,
+    })
+}
+
+fn common_priority(sets: impl IntoIterator<Item = u64>) -> Option<i32> {
+    let common = sets.into_iter().fold(u64::MAX, |common, set| common & set);
+    (common != 0).then_some(common.trailing_zeros() as i32)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+    let lines = lines(input).collect::<Vec<Line>>();
+    if !lines.len().is_multiple_of(3) {
+        return Err(lines[lines.len() - 1].error("last group is incomplete"));
+    }
+    lines
+        .iter()
+        .map(|line| parse_rucksack(line.text).map_err(|reason| line.error(reason)))
+        .collect()
+}
+
+pub fn part1(rucksacks: &[Rucksack]) -> Option<i32> {
+    rucksacks
+        .iter()
+        .map(|rucksack| common_priority([rucksack.first, rucksack.second]))
+        .sum()
+}
+
+pub fn part2(rucksacks: &[Rucksack]) -> Option<i32> {
+    rucksacks
+        .chunks(3)
+        .map(|group| common_priority(group.iter().map(Rucksack::items)))
+        .sum()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<Rucksack>;
+    type Config = ();
+    type Part1 = i32;
+    type Part2 = i32;
+    type Error = NoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        part1(input).ok_or(NoAnswer)
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        part2(input).ok_or(NoAnswer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let rucksacks = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Some(157), part1(&rucksacks));
+    }
+
+    #[test]
+    fn example_part2() {
+        let rucksacks = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Some(70), part2(&rucksacks));
+    }
+
+    #[test]
+    fn malformed_input() {
+        let err = parse("ab1c\nab\ncc\n").unwrap_err();
+        assert_eq!(
+            "line 1: unexpected item type '1' in \"ab1c\"",
+            err.to_string()
+        );
+        let err = parse("aa\nbb\n").unwrap_err();
+        assert_eq!(
+            "line 2: last group is incomplete in \"bb\"",
+            err.to_string()
+        );
+    }
+}
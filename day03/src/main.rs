@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run::<day03::Day03>(include_str!("input.txt"));
+}
@@ -0,0 +1,79 @@
+use std::{convert::Infallible, ops::RangeInclusive};
+
+use aoc_common::{
+    parse::{parse_lines, ParseError},
+    Solution,
+};
+
+fn get_range(str: &str) -> Result<RangeInclusive<i32>, &'static str> {
+    let (start, end) = str.split_once('-').ok_or("missing range delimiter")?;
+    let start: i32 = start.parse().map_err(|_| "range start is not an integer")?;
+    let end: i32 = end.parse().map_err(|_| "range end is not an integer")?;
+    Ok(start..=end)
+}
+
+fn get_ranges(line: &str) -> Result<Pair, &'static str> {
+    let (a, b) = line.split_once(',').ok_or("no comma on line")?;
+    Ok((get_range(a)?, get_range(b)?))
+}
+
+fn fully_overlap((a, b): &&Pair) -> bool {
+    (a.contains(b.start()) && a.contains(b.end())) || (b.contains(a.start()) && b.contains(a.end()))
+}
+
+fn any_overlap((a, b): &&Pair) -> bool {
+    a.end() >= b.start() && b.end() >= a.start()
+}
+
+pub type Pair = (RangeInclusive<i32>, RangeInclusive<i32>);
+
+pub fn parse(input: &str) -> Result<Vec<Pair>, ParseError> {
+    parse_lines(input, get_ranges).collect()
+}
+
+pub fn part1(pairs: &[Pair]) -> usize {
+    pairs.iter().filter(fully_overlap).count()
+}
+
+pub fn part2(pairs: &[Pair]) -> usize {
+    pairs.iter().filter(any_overlap).count()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Vec<Pair>;
+    type Config = ();
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let pairs = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(2, part1(&pairs));
+    }
+
+    #[test]
+    fn example_part2() {
+        let pairs = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(4, part2(&pairs));
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run::<day04::Day04>(include_str!("input.txt"));
+}
@@ -0,0 +1,147 @@
+use aoc_common::{
+    parse::{blocks, Block, Line, ParseError},
+    Solution,
+};
+
+// the line each move came from, for reporting moves that can't be made
+type Move<'a> = (Line<'a>, (usize, usize, usize));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Crane {
+    // moves one crate at a time
+    CrateMover9000,
+    // moves all the crates at once, keeping their order
+    CrateMover9001,
+}
+
+pub struct Procedure<'a> {
+    stacks: Vec<Vec<char>>,
+    moves: Vec<Move<'a>>,
+}
+
+fn build_stacks(desc: Block) -> Result<Vec<Vec<char>>, ParseError> {
+    let mut lines = desc.text.lines().rev();
+    let columns = lines
+        .next()
+        .ok_or_else(|| desc.error("missing stack numbers"))?
+        .split_whitespace()
+        .count();
+
+    let mut result: Vec<Vec<char>> = vec![vec![]; columns];
+
+    for line in lines {
+        for (index, column) in result.iter_mut().enumerate() {
+            if let Some(ch) = line.chars().nth(index * 4 + 1) {
+                if !ch.is_whitespace() {
+                    column.push(ch);
+                }
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+fn parse_move(line: Line, columns: usize) -> Result<(usize, usize, usize), ParseError> {
+    let mut parts = line.text.split_whitespace();
+    let mut field = |keyword: &str| -> Result<usize, ParseError> {
+        if parts.next() != Some(keyword) {
+            return Err(line.error(format!("expected {:?}", keyword)));
+        }
+        let value = parts
+            .next()
+            .ok_or_else(|| line.error(format!("missing value after {:?}", keyword)))?;
+        value.parse().map_err(|err| line.error(err))
+    };
+    let number = field("move")?;
+    let source = field("from")?;
+    let dest = field("to")?;
+    if let Some(stack) = [source, dest]
+        .into_iter()
+        .find(|s| !(1..=columns).contains(s))
+    {
+        return Err(line.error(format!("no stack number {}", stack)));
+    }
+    Ok((number, source, dest))
+}
+
+fn move_stacks(stacks: &mut [Vec<char>], moves: &[Move], crane: Crane) -> Result<(), ParseError> {
+    for &(line, (number, source, dest)) in moves {
+        let source_stack = &mut stacks[source - 1];
+        if source_stack.len() < number {
+            return Err(line.error(format!("stack {} has too few crates", source)));
+        }
+
+        let mut moved = source_stack.split_off(source_stack.len() - number);
+        if crane == Crane::CrateMover9000 {
+            moved.reverse();
+        }
+        stacks[dest - 1].extend(moved);
+    }
+
+    Ok(())
+}
+
+pub fn parse(input: &str) -> Result<Procedure<'_>, ParseError> {
+    let mut blocks = blocks(input);
+    let stacks = blocks.next().expect("split always yields a block");
+    let moves = blocks
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, "missing rearrangement procedure"))?;
+
+    let stacks = build_stacks(stacks)?;
+    let moves = moves
+        .lines()
+        .map(|line| Ok((line, parse_move(line, stacks.len())?)))
+        .collect::<Result<_, ParseError>>()?;
+
+    Ok(Procedure { stacks, moves })
+}
+
+pub fn rearrange(procedure: &Procedure, crane: Crane) -> Result<String, ParseError> {
+    let mut stacks = procedure.stacks.clone();
+    move_stacks(&mut stacks, &procedure.moves, crane)?;
+
+    Ok(String::from_iter(stacks.iter().filter_map(|v| v.last())))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Procedure<'a>;
+    type Config = ();
+    type Part1 = String;
+    type Part2 = String;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        rearrange(input, Crane::CrateMover9000)
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        rearrange(input, Crane::CrateMover9001)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let procedure = parse(include_str!("example.txt")).unwrap();
+        let result = rearrange(&procedure, Crane::CrateMover9000);
+        assert_eq!(Ok("CMZ"), result.as_deref());
+    }
+
+    #[test]
+    fn example_part2() {
+        let procedure = parse(include_str!("example.txt")).unwrap();
+        let result = rearrange(&procedure, Crane::CrateMover9001);
+        assert_eq!(Ok("MCD"), result.as_deref());
+    }
+}
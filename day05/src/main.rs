@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run::<day05::Day05>(include_str!("input.txt"));
+}
@@ -0,0 +1,84 @@
+use aoc_common::{
+    parse::{lines, Line, ParseError},
+    Solution,
+};
+
+fn has_duplicates(chars: &&[char]) -> bool {
+    chars
+        .iter()
+        .enumerate()
+        .any(|(i, ch)| chars.iter().skip(i + 1).any(|x| x == ch))
+}
+
+pub fn parse(input: &str) -> Result<Line<'_>, ParseError> {
+    lines(input)
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, "missing datastream"))
+}
+
+fn find_marker(line: &Line, length: usize) -> Option<usize> {
+    let chars = line.text.chars().collect::<Vec<_>>();
+    chars
+        .windows(length)
+        .position(|window| !has_duplicates(&window))
+        .map(|index| index + length)
+}
+
+pub fn part1(line: &Line) -> Result<usize, ParseError> {
+    find_marker(line, 4).ok_or_else(|| line.error("no start-of-packet marker"))
+}
+
+pub fn part2(line: &Line) -> Result<usize, ParseError> {
+    find_marker(line, 14).ok_or_else(|| line.error("no start-of-message marker"))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = Line<'a>;
+    type Config = ();
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLES: [&str; 5] = [
+        include_str!("example1.txt"),
+        include_str!("example2.txt"),
+        include_str!("example3.txt"),
+        include_str!("example4.txt"),
+        include_str!("example5.txt"),
+    ];
+
+    #[test]
+    fn examples_part1() {
+        for (example, expected) in EXAMPLES.into_iter().zip([7, 5, 6, 10, 11]) {
+            let line = parse(example).unwrap();
+            assert_eq!(Ok(expected), part1(&line));
+        }
+    }
+
+    #[test]
+    fn examples_part2() {
+        for (example, expected) in EXAMPLES.into_iter().zip([19, 23, 23, 29, 26]) {
+            let line = parse(example).unwrap();
+            assert_eq!(Ok(expected), part2(&line));
+        }
+    }
+}
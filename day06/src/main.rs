@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run::<day06::Day06>(include_str!("input.txt"));
+}
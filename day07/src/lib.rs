@@ -0,0 +1,190 @@
+use aoc_common::{
+    parse::{lines, Line, ParseError},
+    NoAnswer, Solution,
+};
+
+pub enum DirectoryEntry {
+    Directory {
+        name: String,
+        contents: Vec<DirectoryEntry>,
+    },
+    File {
+        name: String,
+        size: usize,
+    },
+}
+
+impl DirectoryEntry {
+    fn name(&self) -> &str {
+        match self {
+            DirectoryEntry::Directory { name, contents: _ } => name,
+            DirectoryEntry::File { name, size: _ } => name,
+        }
+    }
+
+    fn size(&self) -> usize {
+        match self {
+            DirectoryEntry::Directory { name: _, contents } => {
+                contents.iter().map(|x| x.size()).sum()
+            }
+            DirectoryEntry::File { name: _, size } => *size,
+        }
+    }
+}
+
+fn read_input<'a, I>(cwd: &mut DirectoryEntry, lines: &mut I) -> Result<(), ParseError>
+where
+    I: Iterator<Item = Line<'a>>,
+{
+    if let DirectoryEntry::Directory { name: _, contents } = cwd {
+        while let Some(line) = lines.next() {
+            match line.text {
+                "$ ls" => (),
+                "$ cd .." => return Ok(()),
+                str if str.starts_with("$ cd ") => {
+                    let dir = &str[5..];
+                    let dir = contents.iter_mut().find(|x| match x {
+                        DirectoryEntry::Directory { name, contents: _ } => name == dir,
+                        _ => false,
+                    });
+                    let dir = dir.ok_or_else(|| line.error("unknown directory"))?;
+                    read_input(dir, lines)?;
+                }
+                str if str.starts_with("dir ") => {
+                    let name = &str[4..];
+                    debug_assert!(!contents.iter().any(|x| x.name() == name));
+                    contents.push(DirectoryEntry::Directory {
+                        name: name.to_string(),
+                        contents: vec![],
+                    });
+                }
+                str => {
+                    let (size, name) = str
+                        .split_once(' ')
+                        .ok_or_else(|| line.error("expected a command or a directory entry"))?;
+                    debug_assert!(!contents.iter().any(|x| x.name() == name));
+                    contents.push(DirectoryEntry::File {
+                        name: name.to_string(),
+                        size: size.parse().map_err(|err| line.error(err))?,
+                    });
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn count_small_directories(cwd: &DirectoryEntry) -> usize {
+    let mut result = 0;
+
+    if let DirectoryEntry::Directory { name: _, contents } = cwd {
+        let size = cwd.size();
+        if size <= 100000 {
+            result += size;
+        }
+
+        result += contents.iter().map(count_small_directories).sum::<usize>();
+    }
+
+    result
+}
+
+fn find_directory_to_delete(cwd: &DirectoryEntry, target: usize) -> Option<usize> {
+    if let DirectoryEntry::Directory { name: _, contents } = cwd {
+        let size = cwd.size();
+        if size < target {
+            return None;
+        }
+
+        let result = contents
+            .iter()
+            .filter_map(|x| find_directory_to_delete(x, target))
+            .min()
+            .unwrap_or(size);
+
+        return Some(result);
+    }
+
+    None
+}
+
+const TOTAL_SPACE_AVAILABLE: usize = 70000000;
+const REQUIRED_SPACE: usize = 30000000;
+const TARGET_SIZE: usize = TOTAL_SPACE_AVAILABLE - REQUIRED_SPACE;
+
+pub fn parse(input: &str) -> Result<DirectoryEntry, ParseError> {
+    let mut root = DirectoryEntry::Directory {
+        name: String::from("/"),
+        contents: vec![],
+    };
+    let mut lines = lines(input);
+    match lines.next() {
+        Some(line) if line.text == "$ cd /" => (),
+        Some(line) => return Err(line.error("expected \"$ cd /\"")),
+        None => return Err(ParseError::end_of_input(input, "missing terminal output")),
+    }
+
+    read_input(&mut root, &mut lines)?;
+
+    Ok(root)
+}
+
+pub fn part1(root: &DirectoryEntry) -> usize {
+    count_small_directories(root)
+}
+
+// None when the files take more space than the disk has
+pub fn part2(root: &DirectoryEntry) -> Option<usize> {
+    let current_usage = root.size();
+    if current_usage > TOTAL_SPACE_AVAILABLE {
+        return None;
+    }
+    let need_to_delete = current_usage.saturating_sub(TARGET_SIZE);
+
+    find_directory_to_delete(root, need_to_delete)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = DirectoryEntry;
+    type Config = ();
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = NoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        part2(input).ok_or(NoAnswer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let root = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(95437, part1(&root));
+    }
+
+    #[test]
+    fn example_part2() {
+        let root = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Some(24933642), part2(&root));
+    }
+
+    #[test]
+    fn larger_than_disk() {
+        let root = parse("$ cd /\n$ ls\n70000001 big.bin\n").unwrap();
+        assert_eq!(None, part2(&root));
+    }
+}
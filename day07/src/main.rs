@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run::<day07::Day07>(include_str!("input.txt"));
+}
@@ -0,0 +1,151 @@
+use std::convert::Infallible;
+
+use aoc_common::{
+    parse::{lines, ParseError},
+    Solution,
+};
+
+pub fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+    let mut width = None;
+    let trees = lines(input)
+        .map(|line| {
+            if let Some(ch) = line.text.chars().find(|ch| !ch.is_ascii_digit()) {
+                return Err(line.error(format!("unexpected tree height {:?}", ch)));
+            }
+            match width {
+                Some(width) if width != line.text.len() => Err(line.error(format!(
+                    "row has {} trees, expected {}",
+                    line.text.len(),
+                    width
+                ))),
+                _ => {
+                    width = Some(line.text.len());
+                    Ok(line.text)
+                }
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if trees.is_empty() {
+        return Err(ParseError::end_of_input(input, "no trees"));
+    }
+    Ok(trees)
+}
+
+fn visible_outside(trees: &[&str], x: usize, y: usize) -> bool {
+    if x == 0 || y == 0 || y + 1 == trees.len() {
+        return true;
+    }
+    let row = trees[y];
+    if x + 1 == row.len() {
+        return true;
+    }
+    let target = row.chars().nth(x).unwrap();
+    let before = &row[..x];
+    if before.chars().all(|c| c < target) {
+        return true;
+    }
+    let after = &row[x + 1..];
+    if after.chars().all(|c| c < target) {
+        return true;
+    }
+    let above = ..y;
+    if trees[above]
+        .iter()
+        .all(|row| row.chars().nth(x).unwrap() < target)
+    {
+        return true;
+    }
+    let below = y + 1..;
+    trees[below]
+        .iter()
+        .all(|row| row.chars().nth(x).unwrap() < target)
+}
+
+fn distance(it: impl Iterator<Item = char> + Clone, target: char) -> usize {
+    match it.clone().enumerate().find(|(_, c)| *c >= target) {
+        Some((i, _)) => i + 1,
+        _ => it.count(),
+    }
+}
+
+fn scenic_score(trees: &[&str], x: usize, y: usize) -> usize {
+    let row = trees[y];
+    let target = row.chars().nth(x).unwrap();
+    let before = distance(row[..x].chars().rev(), target);
+    let after = distance(row.chars().skip(x + 1), target);
+    let above = distance(
+        trees
+            .iter()
+            .take(y)
+            .rev()
+            .map(|row| row.chars().nth(x).unwrap()),
+        target,
+    );
+    let below = distance(
+        trees
+            .iter()
+            .skip(y + 1)
+            .map(|row| row.chars().nth(x).unwrap()),
+        target,
+    );
+    before * after * above * below
+}
+
+fn positions<'a>(trees: &'a [&str]) -> impl Iterator<Item = (usize, usize)> + 'a {
+    trees
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| (0..row.len()).map(move |x| (x, y)))
+}
+
+pub fn part1(trees: &[&str]) -> usize {
+    positions(trees)
+        .filter(|(x, y)| visible_outside(trees, *x, *y))
+        .count()
+}
+
+pub fn part2(trees: &[&str]) -> usize {
+    positions(trees)
+        .map(|(x, y)| scenic_score(trees, x, y))
+        .max()
+        .unwrap()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Vec<&'a str>;
+    type Config = ();
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let trees = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(21, part1(&trees));
+    }
+
+    #[test]
+    fn example_part2() {
+        let trees = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(8, part2(&trees));
+    }
+}
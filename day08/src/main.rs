@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run::<day08::Day08>(include_str!("input.txt"));
+}
@@ -0,0 +1,125 @@
+use std::{collections::HashSet, convert::Infallible};
+
+use aoc_common::{
+    parse::{parse_lines, ParseError},
+    visualize, Direction, Point2, Solution,
+};
+
+fn move_tail(head: Point2, tail: Point2) -> Point2 {
+    match (head.x - tail.x, head.y - tail.y) {
+        (0, 2) => Point2::new(tail.x, tail.y + 1),
+        (0, -2) => Point2::new(tail.x, tail.y - 1),
+        (2, 0) => Point2::new(tail.x + 1, tail.y),
+        (-2, 0) => Point2::new(tail.x - 1, tail.y),
+        (2, 1) | (2, 2) | (1, 2) => Point2::new(tail.x + 1, tail.y + 1),
+        (2, -1) | (2, -2) | (1, -2) => Point2::new(tail.x + 1, tail.y - 1),
+        (-2, 1) | (-2, 2) | (-1, 2) => Point2::new(tail.x - 1, tail.y + 1),
+        (-2, -1) | (-2, -2) | (-1, -2) => Point2::new(tail.x - 1, tail.y - 1),
+        _ => tail,
+    }
+}
+
+fn draw_rope(rope: &[Point2], visited: &HashSet<Point2>) -> String {
+    let (min, max) = Point2::bounds(visited.iter().chain(rope).copied()).unwrap();
+    let (min, max) = visualize::window(min, max, rope[0]);
+    visualize::draw(min, max, |position| {
+        match rope.iter().position(|knot| *knot == position) {
+            Some(0) => 'H',
+            Some(i) if i == rope.len() - 1 => 'T',
+            Some(i) => char::from_digit(i as u32, 10).unwrap_or('*'),
+            None if position == Point2::ORIGIN => 's',
+            None if visited.contains(&position) => '#',
+            None => '.',
+        }
+    })
+}
+
+fn parse_motion(line: &str) -> Result<Motion, String> {
+    let (direction, steps) = line
+        .split_once(' ')
+        .ok_or_else(|| "expected a direction and a number of steps".to_string())?;
+    let direction = match direction.chars().collect::<Vec<_>>()[..] {
+        [letter] => Direction::from_letter(letter),
+        _ => None,
+    }
+    .ok_or_else(|| format!("unknown direction {:?}", direction))?;
+    let steps = steps
+        .parse()
+        .map_err(|err| format!("invalid steps: {}", err))?;
+    Ok((direction, steps))
+}
+
+pub type Motion = (Direction, i32);
+
+pub fn parse(input: &str) -> Result<Vec<Motion>, ParseError> {
+    parse_lines(input, parse_motion).collect()
+}
+
+fn simulate(motions: &[Motion], knots: usize) -> HashSet<Point2> {
+    let mut visited: HashSet<Point2> = HashSet::new();
+    let mut rope = vec![Point2::ORIGIN; knots];
+
+    for &(direction, steps) in motions {
+        for _ in 0..steps {
+            rope[0] = rope[0].step(direction);
+            for i in 1..rope.len() {
+                rope[i] = move_tail(rope[i - 1], rope[i]);
+            }
+            visited.insert(rope[knots - 1]);
+            visualize::frame(format_args!("{} knots", knots), || {
+                draw_rope(&rope, &visited)
+            });
+        }
+    }
+
+    visited
+}
+
+pub fn part1(motions: &[Motion]) -> usize {
+    simulate(motions, 2).len()
+}
+
+pub fn part2(motions: &[Motion]) -> usize {
+    simulate(motions, 10).len()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = Vec<Motion>;
+    type Config = ();
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let motions = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(13, part1(&motions));
+    }
+
+    #[test]
+    fn examples_part2() {
+        let motions = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(1, part2(&motions));
+        let motions = parse(include_str!("example2.txt")).unwrap();
+        assert_eq!(36, part2(&motions));
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run::<day09::Day09>(include_str!("input.txt"));
+}
@@ -0,0 +1,351 @@
+use std::fmt::{Display, Write};
+
+use aoc_common::{
+    ocr::{self, OcrError},
+    parse::{parse_lines, ParseError},
+    render::{self, Image},
+    Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+impl Instruction {
+    pub fn cycles(self) -> usize {
+        match self {
+            Instruction::Noop => 1,
+            Instruction::Addx(_) => 2,
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Instruction::Noop => write!(f, "noop"),
+            Instruction::Addx(value) => write!(f, "addx {}", value),
+        }
+    }
+}
+
+fn parse_instruction(line: &str) -> Result<Instruction, String> {
+    let mut parts = line.split_whitespace();
+    let instruction = match parts.next() {
+        Some("noop") => Instruction::Noop,
+        Some("addx") => {
+            let value = parts.next().ok_or("missing addx operand")?;
+            Instruction::Addx(
+                value
+                    .parse()
+                    .map_err(|err| format!("invalid operand: {}", err))?,
+            )
+        }
+        _ => return Err("unexpected op".to_string()),
+    };
+    match parts.next() {
+        Some(extra) => Err(format!("unexpected operand {:?}", extra)),
+        None => Ok(instruction),
+    }
+}
+
+// something watching the X register, told its value during every cycle
+pub trait Observer {
+    fn observe(&mut self, cycle: usize, x: i32);
+}
+
+// one cycle of a program: the instruction busy during it and how far along it is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub cycle: usize,
+    pub x: i32,
+    pub address: usize,
+    pub instruction: Instruction,
+    pub busy: usize,
+}
+
+pub struct Cpu {
+    program: Vec<Instruction>,
+}
+
+impl Cpu {
+    pub fn new(program: Vec<Instruction>) -> Cpu {
+        Cpu { program }
+    }
+
+    pub fn program(&self) -> &[Instruction] {
+        &self.program
+    }
+
+    pub fn steps(&self) -> impl Iterator<Item = Step> + '_ {
+        let mut x = 1;
+        self.program
+            .iter()
+            .enumerate()
+            .flat_map(move |(address, &instruction)| {
+                let during = x;
+                if let Instruction::Addx(value) = instruction {
+                    x += value;
+                }
+                (1..=instruction.cycles()).map(move |busy| (address, instruction, busy, during))
+            })
+            .zip(1..)
+            .map(|((address, instruction, busy, x), cycle)| Step {
+                cycle,
+                x,
+                address,
+                instruction,
+                busy,
+            })
+    }
+
+    // the value of the X register during each cycle, starting with the first
+    pub fn states(&self) -> impl Iterator<Item = (usize, i32)> + '_ {
+        self.steps().map(|step| (step.cycle, step.x))
+    }
+
+    pub fn run(&self, observers: &mut [&mut dyn Observer]) {
+        for (cycle, x) in self.states() {
+            for observer in observers.iter_mut() {
+                observer.observe(cycle, x);
+            }
+        }
+    }
+
+    // a line per cycle with X and the instruction being executed
+    pub fn trace(&self) -> String {
+        let mut trace = format!("{:>5}  {:>4}  {:>4}  instruction\n", "cycle", "x", "addr");
+        for step in self.steps() {
+            let _ = write!(
+                trace,
+                "{:>5}  {:>4}  {:>4}  {}",
+                step.cycle, step.x, step.address, step.instruction
+            );
+            if step.instruction.cycles() > 1 {
+                let _ = write!(trace, " ({}/{})", step.busy, step.instruction.cycles());
+            }
+            trace.push('\n');
+        }
+        trace
+    }
+}
+
+// the disassembled program, an instruction per line
+impl Display for Cpu {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (address, instruction) in self.program.iter().enumerate() {
+            writeln!(f, "{:>4}  {}", address, instruction)?;
+        }
+        Ok(())
+    }
+}
+
+// sums cycle * X for the 20th cycle and every 40th after it up to the 220th
+pub struct SignalStrength {
+    first: usize,
+    interval: usize,
+    last: usize,
+    total: i32,
+}
+
+impl SignalStrength {
+    pub fn new(first: usize, interval: usize, last: usize) -> SignalStrength {
+        SignalStrength {
+            first,
+            interval,
+            last,
+            total: 0,
+        }
+    }
+
+    pub fn total(&self) -> i32 {
+        self.total
+    }
+}
+
+impl Default for SignalStrength {
+    fn default() -> Self {
+        SignalStrength::new(20, 40, 220)
+    }
+}
+
+impl Observer for SignalStrength {
+    fn observe(&mut self, cycle: usize, x: i32) {
+        if (self.first..=self.last).contains(&cycle)
+            && (cycle - self.first).is_multiple_of(self.interval)
+        {
+            self.total += cycle as i32 * x;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Screen {
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Default for Screen {
+    fn default() -> Self {
+        Screen {
+            width: 40,
+            height: 6,
+        }
+    }
+}
+
+// the beam draws a pixel per cycle, lit when the three pixel wide sprite centred on
+// X covers it, and starts over at the top left once the screen is full
+pub struct Crt {
+    screen: Screen,
+    pixels: Vec<Vec<char>>,
+}
+
+impl Crt {
+    pub fn new(screen: Screen) -> Crt {
+        Crt {
+            screen,
+            pixels: vec![vec!['.'; screen.width]; screen.height],
+        }
+    }
+
+    pub fn rows(&self) -> Vec<String> {
+        self.pixels.iter().map(|row| row.iter().collect()).collect()
+    }
+
+    // lit pixels, dark ones and those the beam has not reached yet
+    fn render(&self) -> Image {
+        Image::from_fn(self.screen.width, self.screen.height, |x, y| {
+            match self.pixels[y][x] {
+                '#' => [120, 255, 120],
+                '.' => [10, 10, 10],
+                _ => [30, 40, 30],
+            }
+        })
+    }
+}
+
+impl Observer for Crt {
+    fn observe(&mut self, cycle: usize, x: i32) {
+        let Screen { width, height } = self.screen;
+        if width * height == 0 {
+            return;
+        }
+        let position = (cycle - 1) % (width * height);
+        let (row, column) = (position / width, position % width);
+        self.pixels[row][column] = match x - column as i32 {
+            -1..=1 => '#',
+            _ => ' ',
+        };
+        render::frame(|| self.render());
+    }
+}
+
+pub fn parse(input: &str) -> Result<Cpu, ParseError> {
+    Ok(Cpu::new(
+        parse_lines(input, parse_instruction).collect::<Result<_, _>>()?,
+    ))
+}
+
+pub fn part1(cpu: &Cpu) -> i32 {
+    let mut signal = SignalStrength::default();
+    cpu.run(&mut [&mut signal]);
+    signal.total()
+}
+
+pub fn draw_screen(cpu: &Cpu, screen: Screen) -> Vec<String> {
+    let mut crt = Crt::new(screen);
+    cpu.run(&mut [&mut crt]);
+    render::last(|| crt.render());
+    crt.rows()
+}
+
+pub fn part2(cpu: &Cpu, screen: Screen) -> Result<String, OcrError> {
+    ocr::read(&draw_screen(cpu, screen))
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Cpu;
+    type Config = Screen;
+    type Part1 = i32;
+    type Part2 = String;
+    type Error = OcrError;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>, config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        part2(input, *config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let cpu = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(13140, part1(&cpu));
+    }
+
+    #[test]
+    fn example_part2() {
+        let cpu = parse(include_str!("example.txt")).unwrap();
+        let expected = vec![
+            "##  ##  ##  ##  ##  ##  ##  ##  ##  ##  ",
+            "###   ###   ###   ###   ###   ###   ### ",
+            "####    ####    ####    ####    ####    ",
+            "#####     #####     #####     #####     ",
+            "######      ######      ######      ####",
+            "#######       #######       #######     ",
+        ];
+        assert_eq!(expected, draw_screen(&cpu, Screen::default()));
+    }
+
+    #[test]
+    fn small_program() {
+        let cpu = parse("noop\naddx 3\naddx -5\n").unwrap();
+        assert_eq!(
+            vec![(1, 1), (2, 1), (3, 1), (4, 4), (5, 4)],
+            cpu.states().collect::<Vec<_>>()
+        );
+        assert_eq!("   0  noop\n   1  addx 3\n   2  addx -5\n", cpu.to_string());
+        assert_eq!(
+            "cycle     x  addr  instruction\n\
+             \x20   1     1     0  noop\n\
+             \x20   2     1     1  addx 3 (1/2)\n\
+             \x20   3     1     1  addx 3 (2/2)\n\
+             \x20   4     4     2  addx -5 (1/2)\n\
+             \x20   5     4     2  addx -5 (2/2)\n",
+            cpu.trace()
+        );
+    }
+
+    #[test]
+    fn other_screens() {
+        let cpu = parse("addx 1\nnoop\naddx 2\n").unwrap();
+        assert_eq!(
+            vec!["##", " #", " ."],
+            draw_screen(
+                &cpu,
+                Screen {
+                    width: 2,
+                    height: 3
+                }
+            )
+        );
+        let mut signal = SignalStrength::new(1, 2, 5);
+        cpu.run(&mut [&mut signal]);
+        assert_eq!(1 + 3 * 2 + 5 * 2, signal.total());
+    }
+}
@@ -0,0 +1,16 @@
+use std::env;
+
+use aoc_common::{input, take_options, OrExit};
+use day10::{parse, Day10};
+
+// --trace lists the value of X and the instruction being executed for every cycle
+// instead of the answers
+fn main() {
+    let args = take_options(env::args().skip(1));
+    if !args.iter().any(|arg| arg == "--trace") {
+        return aoc_common::run_with::<Day10>(args, include_str!("input.txt"));
+    }
+    let args = args.into_iter().filter(|arg| arg != "--trace");
+    let input = input::from_args(args, include_str!("input.txt")).or_exit();
+    print!("{}", parse(&input).or_exit().trace());
+}
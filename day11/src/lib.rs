@@ -0,0 +1,738 @@
+use std::{collections::HashMap, error::Error, fmt::Display, num::NonZeroU64};
+
+use aoc_common::{
+    parse::{blocks, Block, Line, ParseError},
+    BigUint, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Multiply,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Old,
+    Number(u64),
+}
+
+// new = old <operator> <operand>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Operation {
+    pub operator: Operator,
+    pub operand: Operand,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Test {
+    pub divisor: u64,
+    pub if_true: usize,
+    pub if_false: usize,
+}
+
+impl Test {
+    // the monkey an item with `worry` is thrown to
+    fn target<W: Worry>(&self, worry: &W) -> usize {
+        if worry.is_multiple_of(self.divisor) {
+            self.if_true
+        } else {
+            self.if_false
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Monkey {
+    pub id: usize,
+    pub items: Vec<u64>,
+    pub operation: Operation,
+    pub test: Test,
+}
+
+impl Display for Operator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operator::Add => write!(f, "+"),
+            Operator::Multiply => write!(f, "*"),
+        }
+    }
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operand::Old => f.pad("old"),
+            Operand::Number(num) => f.pad(&num.to_string()),
+        }
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "new = old {} {}", self.operator, self.operand)
+    }
+}
+
+impl Display for Test {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "divisible by {}", self.divisor)?;
+        writeln!(f, "    If true: throw to monkey {}", self.if_true)?;
+        write!(f, "    If false: throw to monkey {}", self.if_false)
+    }
+}
+
+// the monkey's notes as the puzzle writes them
+impl Display for Monkey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let items = self
+            .items
+            .iter()
+            .map(|item| item.to_string())
+            .collect::<Vec<_>>();
+        writeln!(f, "Monkey {}:", self.id)?;
+        writeln!(f, "  Starting items: {}", items.join(", "))?;
+        writeln!(f, "  Operation: {}", self.operation)?;
+        write!(f, "  Test: {}", self.test)
+    }
+}
+
+// the notes of all monkeys, separated by blank lines like the input
+pub fn notes(monkeys: &[Monkey]) -> String {
+    let notes = monkeys
+        .iter()
+        .map(|monkey| monkey.to_string())
+        .collect::<Vec<_>>();
+    notes.join("\n\n") + "\n"
+}
+
+// a line per monkey of what it does with an item
+pub fn summary(monkeys: &[Monkey]) -> String {
+    let mut summary = String::new();
+    for monkey in monkeys {
+        let Test {
+            divisor,
+            if_true,
+            if_false,
+        } = monkey.test;
+        let items = monkey
+            .items
+            .iter()
+            .map(|item| item.to_string())
+            .collect::<Vec<_>>();
+        summary.push_str(&format!(
+            "{}: old {} {:<4} -> {} if divisible by {:<2} else {}  [{}]\n",
+            monkey.id,
+            monkey.operation.operator,
+            monkey.operation.operand,
+            if_true,
+            divisor,
+            if_false,
+            items.join(", ")
+        ));
+    }
+    summary
+}
+
+// the next line of the monkey's notes, without its expected prefix
+fn field<'a, I>(
+    lines: &mut I,
+    block: &Block,
+    prefix: &str,
+) -> Result<(Line<'a>, &'a str), ParseError>
+where
+    I: Iterator<Item = Line<'a>>,
+{
+    let line = lines
+        .next()
+        .ok_or_else(|| block.error(format!("missing {:?}", prefix.trim())))?;
+    let value = line
+        .text
+        .strip_prefix(prefix)
+        .ok_or_else(|| line.error(format!("expected {:?}", prefix.trim())))?;
+    Ok((line, value))
+}
+
+fn parse_starting_items(line: Line, items: &str) -> Result<Vec<u64>, ParseError> {
+    items
+        .split(", ")
+        .map(|s| s.parse().map_err(|err| line.error(err)))
+        .collect()
+}
+
+fn parse_operation(line: Line, operation: &str) -> Result<Operation, ParseError> {
+    let (op, num) = operation
+        .split_once(' ')
+        .ok_or_else(|| line.error("expected an operator and an operand"))?;
+    let operator = match op {
+        "+" => Operator::Add,
+        "*" => Operator::Multiply,
+        _ => return Err(line.error(format!("unknown operation {:?}", op))),
+    };
+    let operand = match num {
+        "old" => Operand::Old,
+        _ => Operand::Number(num.parse().map_err(|err| line.error(err))?),
+    };
+    Ok(Operation { operator, operand })
+}
+
+// a monkey throwing to itself would inspect the same item again and again
+fn parse_target(line: Line, target: &str, id: usize) -> Result<usize, ParseError> {
+    let target = target.parse().map_err(|err| line.error(err))?;
+    if target == id {
+        return Err(line.error(format!("monkey {} throws to itself", id)));
+    }
+    Ok(target)
+}
+
+fn parse_monkey(block: Block, index: usize) -> Result<Monkey, ParseError> {
+    let mut lines = block.lines();
+    let (line, id) = field(&mut lines, &block, "Monkey ")?;
+    let id: usize = id
+        .strip_suffix(':')
+        .ok_or_else(|| line.error("expected ':'"))?
+        .parse()
+        .map_err(|err| line.error(err))?;
+    if id != index {
+        return Err(line.error(format!("expected monkey {}", index)));
+    }
+    let (line, items) = field(&mut lines, &block, "  Starting items: ")?;
+    let items = parse_starting_items(line, items)?;
+    let (line, operation) = field(&mut lines, &block, "  Operation: new = old ")?;
+    let operation = parse_operation(line, operation)?;
+    let (line, divisor) = field(&mut lines, &block, "  Test: divisible by ")?;
+    let divisor: u64 = divisor.parse().map_err(|err| line.error(err))?;
+    if divisor == 0 {
+        return Err(line.error("cannot test divisibility by zero"));
+    }
+    let (line, if_true) = field(&mut lines, &block, "    If true: throw to monkey ")?;
+    let if_true = parse_target(line, if_true, id)?;
+    let (line, if_false) = field(&mut lines, &block, "    If false: throw to monkey ")?;
+    let if_false = parse_target(line, if_false, id)?;
+    if let Some(line) = lines.next() {
+        return Err(line.error("unexpected line"));
+    }
+    Ok(Monkey {
+        id,
+        items,
+        operation,
+        test: Test {
+            divisor,
+            if_true,
+            if_false,
+        },
+    })
+}
+
+fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let monkeys = blocks(input)
+        .enumerate()
+        .map(|(index, block)| parse_monkey(block, index))
+        .collect::<Result<Vec<_>, _>>()?;
+    for (block, monkey) in blocks(input).zip(&monkeys) {
+        let targets = [monkey.test.if_true, monkey.test.if_false];
+        if let Some(target) = targets.iter().find(|&&t| t >= monkeys.len()) {
+            return Err(block.error(format!("throws to missing monkey {}", target)));
+        }
+    }
+    Ok(monkeys)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    parse_monkeys(input)
+}
+
+// how a monkey's relief after inspecting an item lowers the worry level
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relief {
+    // never by zero, which the type rules out
+    Divide(NonZeroU64),
+    // modulo the product of the divisors, which leaves every test's outcome as is
+    Modulus,
+    // the exact levels, which grow out of any fixed width integer within a few rounds
+    None,
+}
+
+// a third of the level, rounded down, as in the first part
+const THIRD: Relief = Relief::Divide(NonZeroU64::new(3).unwrap());
+
+// the worry levels outgrow 64 bits where only the product of the divisors keeps
+// them down, as that product needs to fit itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorryOverflow;
+
+impl Display for WorryOverflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "worry levels do not fit in 64 bits")
+    }
+}
+
+impl Error for WorryOverflow {}
+
+// the product of the divisors, when it fits
+fn divisor_product(monkeys: &[Monkey]) -> Option<u64> {
+    monkeys.iter().try_fold(1u64, |product, monkey| {
+        product.checked_mul(monkey.test.divisor)
+    })
+}
+
+// a worry level as the monkeys handle it, None when it no longer fits
+pub trait Worry: Clone + Display {
+    fn new(level: u64) -> Self;
+    fn apply(&self, operation: Operation) -> Option<Self>;
+    fn relieved(self, relief: Relief, modulus: Option<u64>) -> Option<Self>;
+    fn is_multiple_of(&self, divisor: u64) -> bool;
+}
+
+impl Worry for u64 {
+    fn new(level: u64) -> Self {
+        level
+    }
+
+    fn apply(&self, operation: Operation) -> Option<Self> {
+        let operand = match operation.operand {
+            Operand::Old => *self,
+            Operand::Number(num) => num,
+        };
+        match operation.operator {
+            Operator::Add => self.checked_add(operand),
+            Operator::Multiply => self.checked_mul(operand),
+        }
+    }
+
+    fn relieved(self, relief: Relief, modulus: Option<u64>) -> Option<Self> {
+        match relief {
+            Relief::Divide(divisor) => Some(self / divisor),
+            Relief::Modulus => Some(self % modulus?),
+            Relief::None => Some(self),
+        }
+    }
+
+    fn is_multiple_of(&self, divisor: u64) -> bool {
+        u64::is_multiple_of(*self, divisor)
+    }
+}
+
+impl Worry for BigUint {
+    fn new(level: u64) -> Self {
+        BigUint::from(level)
+    }
+
+    fn apply(&self, operation: Operation) -> Option<Self> {
+        Some(match (operation.operator, operation.operand) {
+            (Operator::Add, Operand::Number(num)) => self + num,
+            (Operator::Add, Operand::Old) => self * 2,
+            (Operator::Multiply, Operand::Number(num)) => self * num,
+            (Operator::Multiply, Operand::Old) => self * self,
+        })
+    }
+
+    fn relieved(self, relief: Relief, modulus: Option<u64>) -> Option<Self> {
+        match relief {
+            Relief::Divide(divisor) => Some(&self / divisor.get()),
+            Relief::Modulus => Some(BigUint::from(&self % modulus?)),
+            Relief::None => Some(self),
+        }
+    }
+
+    fn is_multiple_of(&self, divisor: u64) -> bool {
+        self % divisor == 0
+    }
+}
+
+// the items each monkey holds and how many it has inspected so far, after `round`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot<W> {
+    pub round: usize,
+    pub items: Vec<Vec<W>>,
+    pub inspections: Vec<usize>,
+}
+
+impl<W: Display> Display for Snapshot<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "== After round {} ==", self.round)?;
+        for (i, items) in self.items.iter().enumerate() {
+            write!(f, "Monkey {}:", i)?;
+            for (j, item) in items.iter().enumerate() {
+                write!(f, "{}{}", if j == 0 { " " } else { ", " }, item)?;
+            }
+            writeln!(f)?;
+        }
+        for (i, inspections) in self.inspections.iter().enumerate() {
+            writeln!(f, "Monkey {} inspected items {} times.", i, inspections)?;
+        }
+        Ok(())
+    }
+}
+
+// the monkeys playing keep away, a round per call to `next`, which stops after a
+// round the worry levels overflowed in
+pub struct Simulation<'a, W> {
+    monkeys: &'a [Monkey],
+    relief: Relief,
+    modulus: Option<u64>,
+    round: usize,
+    items: Vec<Vec<W>>,
+    inspections: Vec<usize>,
+    overflowed: bool,
+}
+
+impl<'a, W: Worry> Simulation<'a, W> {
+    pub fn new(monkeys: &'a [Monkey], relief: Relief) -> Simulation<'a, W> {
+        Simulation {
+            monkeys,
+            relief,
+            modulus: divisor_product(monkeys),
+            round: 0,
+            items: monkeys
+                .iter()
+                .map(|monkey| monkey.items.iter().map(|&level| W::new(level)).collect())
+                .collect(),
+            inspections: vec![0; monkeys.len()],
+            overflowed: false,
+        }
+    }
+
+    // an overflow leaves the round half played
+    pub fn play_round(&mut self) -> Result<(), WorryOverflow> {
+        for (i, monkey) in self.monkeys.iter().enumerate() {
+            self.inspections[i] += self.items[i].len();
+            let held = std::mem::take(&mut self.items[i]);
+            for worry in held {
+                let worry = worry
+                    .apply(monkey.operation)
+                    .and_then(|worry| worry.relieved(self.relief, self.modulus))
+                    .ok_or_else(|| {
+                        self.overflowed = true;
+                        WorryOverflow
+                    })?;
+                self.items[monkey.test.target(&worry)].push(worry);
+            }
+        }
+        self.round += 1;
+        Ok(())
+    }
+
+    pub fn snapshot(&self) -> Snapshot<W> {
+        Snapshot {
+            round: self.round,
+            items: self.items.clone(),
+            inspections: self.inspections.clone(),
+        }
+    }
+
+    // the product of the two highest inspection counts
+    pub fn monkey_business(&self) -> usize {
+        let mut inspections = self.inspections.clone();
+        inspections.sort();
+        inspections.iter().rev().take(2).product()
+    }
+}
+
+impl<W: Worry> Iterator for Simulation<'_, W> {
+    type Item = Result<Snapshot<W>, WorryOverflow>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.overflowed {
+            return None;
+        }
+        Some(self.play_round().map(|()| self.snapshot()))
+    }
+}
+
+fn play<W: Worry>(
+    monkeys: &[Monkey],
+    rounds: usize,
+    relief: Relief,
+) -> Result<usize, WorryOverflow> {
+    let mut simulation = Simulation::<W>::new(monkeys, relief);
+    for _ in 0..rounds {
+        simulation.play_round()?;
+    }
+    Ok(simulation.monkey_business())
+}
+
+// levels only become big integers when nothing keeps them small, or when dividing
+// them does not keep them within 64 bits
+pub fn monkey_business(
+    monkeys: &[Monkey],
+    rounds: usize,
+    relief: Relief,
+) -> Result<usize, WorryOverflow> {
+    match relief {
+        Relief::None => play::<BigUint>(monkeys, rounds, relief),
+        _ => play::<u64>(monkeys, rounds, relief)
+            .or_else(|_| play::<BigUint>(monkeys, rounds, relief)),
+    }
+}
+
+// one round of a single item under `Relief::Modulus`, from the monkey holding it at
+// the start of the round until it is thrown back to one that already had its turn
+fn item_round(
+    monkeys: &[Monkey],
+    modulus: u64,
+    (mut monkey, mut worry): (usize, u64),
+    inspections: &mut [usize],
+) -> Result<(usize, u64), WorryOverflow> {
+    loop {
+        inspections[monkey] += 1;
+        worry = worry
+            .apply(monkeys[monkey].operation)
+            .and_then(|worry| worry.relieved(Relief::Modulus, Some(modulus)))
+            .ok_or(WorryOverflow)?;
+        let next = monkeys[monkey].test.target(&worry);
+        if next <= monkey {
+            return Ok((next, worry));
+        }
+        monkey = next;
+    }
+}
+
+// how often each monkey inspects one item within `rounds`; as items never affect
+// each other and only have so many states, every item ends up going round in a
+// cycle that is repeated instead of played
+fn item_inspections(
+    monkeys: &[Monkey],
+    modulus: u64,
+    start: (usize, u64),
+    rounds: usize,
+) -> Result<Vec<usize>, WorryOverflow> {
+    let mut state = start;
+    let mut seen = HashMap::from([(state, 0)]);
+    // the inspections after each round so far
+    let mut totals = vec![vec![0; monkeys.len()]];
+
+    for round in 1..=rounds {
+        let mut inspections = totals[round - 1].clone();
+        state = item_round(monkeys, modulus, state, &mut inspections)?;
+        totals.push(inspections);
+
+        if let Some(&first) = seen.get(&state) {
+            let length = round - first;
+            let (cycles, rest) = ((rounds - round) / length, (rounds - round) % length);
+            return Ok((0..monkeys.len())
+                .map(|i| {
+                    let cycle = totals[round][i] - totals[first][i];
+                    totals[round][i] + cycles * cycle + totals[first + rest][i] - totals[first][i]
+                })
+                .collect());
+        }
+        seen.insert(state, round);
+    }
+
+    Ok(totals.pop().unwrap())
+}
+
+// the same as `monkey_business` with `Relief::Modulus`, but in no time for any
+// number of rounds, where the product needs more than 64 bits
+pub fn extrapolated_monkey_business(
+    monkeys: &[Monkey],
+    rounds: usize,
+) -> Result<u128, WorryOverflow> {
+    let modulus = divisor_product(monkeys).ok_or(WorryOverflow)?;
+    let mut inspections = vec![0; monkeys.len()];
+    for (i, monkey) in monkeys.iter().enumerate() {
+        for &worry in &monkey.items {
+            let counts = item_inspections(monkeys, modulus, (i, worry % modulus), rounds)?;
+            for (total, count) in inspections.iter_mut().zip(counts) {
+                *total += count;
+            }
+        }
+    }
+    inspections.sort();
+    Ok(inspections
+        .iter()
+        .rev()
+        .take(2)
+        .map(|&n| n as u128)
+        .product())
+}
+
+pub fn part1(monkeys: &[Monkey], rounds: usize) -> Result<usize, WorryOverflow> {
+    monkey_business(monkeys, rounds, THIRD)
+}
+
+pub fn part2(monkeys: &[Monkey], rounds: usize) -> Result<u128, WorryOverflow> {
+    extrapolated_monkey_business(monkeys, rounds)
+}
+
+pub struct Config {
+    pub part1_rounds: usize,
+    pub part2_rounds: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            part1_rounds: 20,
+            part2_rounds: 10000,
+        }
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Vec<Monkey>;
+    type Config = Config;
+    type Part1 = usize;
+    type Part2 = u128;
+    type Error = WorryOverflow;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        part1(input, config.part1_rounds)
+    }
+
+    fn part2(input: &Self::Input<'_>, config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        part2(input, config.part2_rounds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let monkeys = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Ok(10605), part1(&monkeys, 20));
+    }
+
+    #[test]
+    fn example_part2() {
+        let monkeys = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Ok(2713310158), part2(&monkeys, 10000));
+    }
+
+    #[test]
+    fn extrapolated_rounds() {
+        for input in [include_str!("example.txt"), include_str!("input.txt")] {
+            let monkeys = parse(input).unwrap();
+            for rounds in [0, 1, 20, 1000, 10000] {
+                assert_eq!(
+                    monkey_business(&monkeys, rounds, Relief::Modulus).map(|n| n as u128),
+                    extrapolated_monkey_business(&monkeys, rounds)
+                );
+            }
+        }
+
+        let monkeys = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(
+            Ok(27142382301385558311211320),
+            part2(&monkeys, 10_usize.pow(12))
+        );
+    }
+
+    #[test]
+    fn rounds() {
+        let monkeys = parse(include_str!("example.txt")).unwrap();
+        let mut simulation = Simulation::<u64>::new(&monkeys, THIRD);
+        assert_eq!(
+            "== After round 1 ==\n\
+             Monkey 0: 20, 23, 27, 26\n\
+             Monkey 1: 2080, 25, 167, 207, 401, 1046\n\
+             Monkey 2:\n\
+             Monkey 3:\n\
+             Monkey 0 inspected items 2 times.\n\
+             Monkey 1 inspected items 4 times.\n\
+             Monkey 2 inspected items 3 times.\n\
+             Monkey 3 inspected items 5 times.\n",
+            simulation.next().unwrap().unwrap().to_string()
+        );
+
+        let snapshot = Simulation::<u64>::new(&monkeys, Relief::Modulus)
+            .nth(19)
+            .unwrap()
+            .unwrap();
+        assert_eq!(20, snapshot.round);
+        assert_eq!(vec![99, 97, 8, 103], snapshot.inspections);
+    }
+
+    #[test]
+    fn exact_worry_levels() {
+        let monkeys = parse(include_str!("example.txt")).unwrap();
+        let exact = Simulation::<BigUint>::new(&monkeys, Relief::None)
+            .nth(19)
+            .unwrap()
+            .unwrap();
+        assert_eq!(vec![99, 97, 8, 103], exact.inspections);
+        assert!(exact.items.iter().flatten().any(|level| level.bits() > 64));
+        assert_eq!(
+            monkey_business(&monkeys, 30, Relief::Modulus),
+            monkey_business(&monkeys, 30, Relief::None)
+        );
+    }
+
+    #[test]
+    fn overflowing_worry_levels() {
+        let monkeys = parse(include_str!("example.txt")).unwrap();
+        let mut simulation = Simulation::<u64>::new(&monkeys, THIRD);
+        let overflow = simulation.position(|snapshot| snapshot.is_err()).unwrap();
+        assert!(overflow < 200);
+        assert_eq!(None, simulation.next());
+        assert_eq!(
+            play::<BigUint>(&monkeys, 200, THIRD),
+            monkey_business(&monkeys, 200, THIRD)
+        );
+
+        // the divisors' product does not fit, so nothing keeps the levels down
+        let input = include_str!("example.txt")
+            .replacen("divisible by 23", "divisible by 4294967311", 1)
+            .replacen("divisible by 19", "divisible by 4294967357", 1);
+        let monkeys = parse(&input).unwrap();
+        assert_eq!(
+            Err(WorryOverflow),
+            monkey_business(&monkeys, 20, Relief::Modulus)
+        );
+        assert_eq!(Err(WorryOverflow), part2(&monkeys, 20));
+    }
+
+    #[test]
+    fn notes_round_trip() {
+        let input = include_str!("example.txt");
+        let monkeys = parse(input).unwrap();
+        assert_eq!(input, notes(&monkeys));
+        assert_eq!(
+            Operation {
+                operator: Operator::Multiply,
+                operand: Operand::Old
+            },
+            monkeys[2].operation
+        );
+        assert_eq!("new = old * old", monkeys[2].operation.to_string());
+        assert!(summary(&monkeys)
+            .starts_with("0: old * 19   -> 2 if divisible by 23 else 3  [79, 98]\n"));
+
+        let input = include_str!("input.txt");
+        assert_eq!(input, notes(&parse(input).unwrap()));
+    }
+
+    #[test]
+    fn invalid_targets() {
+        let input =
+            include_str!("example.txt").replacen("throw to monkey 2", "throw to monkey 0", 1);
+        let err = parse(&input).err().unwrap();
+        assert_eq!(5, err.line());
+        assert_eq!("monkey 0 throws to itself", err.reason());
+
+        let input =
+            include_str!("example.txt").replacen("throw to monkey 3", "throw to monkey 4", 1);
+        let err = parse(&input).err().unwrap();
+        assert_eq!("throws to missing monkey 4", err.reason());
+
+        let input = include_str!("example.txt").replacen("Monkey 1:", "Monkey 7:", 1);
+        assert_eq!("expected monkey 1", parse(&input).err().unwrap().reason());
+    }
+
+    #[test]
+    fn malformed_input() {
+        let input = include_str!("example.txt").replacen("old * 19", "old ^ 19", 1);
+        let err = parse(&input).err().unwrap();
+        assert_eq!(3, err.line());
+        assert_eq!("  Operation: new = old ^ 19", err.text());
+    }
+}
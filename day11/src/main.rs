@@ -0,0 +1,15 @@
+use std::env;
+
+use aoc_common::{input, take_options, OrExit};
+use day11::{parse, summary, Day11};
+
+// --summary lists what each monkey does with an item instead of the answers
+fn main() {
+    let args = take_options(env::args().skip(1));
+    if !args.iter().any(|arg| arg == "--summary") {
+        return aoc_common::run_with::<Day11>(args, include_str!("input.txt"));
+    }
+    let args = args.into_iter().filter(|arg| arg != "--summary");
+    let input = input::from_args(args, include_str!("input.txt")).or_exit();
+    print!("{}", summary(&parse(&input).or_exit()));
+}
@@ -0,0 +1,142 @@
+use std::collections::VecDeque;
+
+use aoc_common::{Grid, NoAnswer, ParseError, Point2, Solution};
+
+pub struct Map {
+    start: Point2,
+    target: Point2,
+    heights: Grid<i32>,
+}
+
+fn parse_map(input: &str) -> Result<Map, ParseError> {
+    let mut start = None;
+    let mut target = None;
+    let heights = Grid::parse(input, |position, ch| match ch {
+        'S' => {
+            start = Some(position);
+            Some(0)
+        }
+        'E' => {
+            target = Some(position);
+            Some(25)
+        }
+        'a'..='z' => Some(ch as i32 - 'a' as i32),
+        _ => None,
+    })?;
+    Ok(Map {
+        start: start.ok_or_else(|| ParseError::end_of_input(input, "missing start 'S'"))?,
+        target: target.ok_or_else(|| ParseError::end_of_input(input, "missing target 'E'"))?,
+        heights,
+    })
+}
+
+#[derive(Copy, Clone)]
+struct State {
+    steps: i32,
+    height: i32,
+    position: Point2,
+}
+
+// breadth-first from `from` until a location satisfies `is_target`; `can_step`
+// is given the heights of the current and the next location
+fn shortest_path<T, S>(map: &Map, from: Point2, is_target: T, can_step: S) -> Option<i32>
+where
+    T: Fn(Point2, i32) -> bool,
+    S: Fn(i32, i32) -> bool,
+{
+    let mut visited = Grid::new(map.heights.width(), map.heights.height(), false);
+    visited[from] = true;
+    let mut queue = VecDeque::new();
+    queue.push_back(State {
+        steps: 0,
+        height: map.heights[from],
+        position: from,
+    });
+
+    while let Some(State {
+        steps,
+        height,
+        position,
+    }) = queue.pop_front()
+    {
+        if is_target(position, height) {
+            return Some(steps);
+        }
+
+        for next in map.heights.neighbours4(position) {
+            let next_height = map.heights[next];
+            if !visited[next] && can_step(height, next_height) {
+                visited[next] = true;
+                queue.push_back(State {
+                    steps: steps + 1,
+                    height: next_height,
+                    position: next,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    parse_map(input)
+}
+
+pub fn part1(map: &Map) -> Option<i32> {
+    shortest_path(
+        map,
+        map.start,
+        |position, _| position == map.target,
+        |height, next| next - height <= 1,
+    )
+}
+
+// walks down from the target to the nearest lowest square
+pub fn part2(map: &Map) -> Option<i32> {
+    shortest_path(
+        map,
+        map.target,
+        |_, height| height == 0,
+        |height, next| next - height >= -1,
+    )
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Map;
+    type Config = ();
+    type Part1 = i32;
+    type Part2 = i32;
+    type Error = NoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        part1(input).ok_or(NoAnswer)
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        part2(input).ok_or(NoAnswer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let map = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Some(31), part1(&map));
+    }
+
+    #[test]
+    fn example_part2() {
+        let map = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Some(29), part2(&map));
+    }
+}
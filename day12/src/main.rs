@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run::<day12::Day12>(include_str!("input.txt"));
+}
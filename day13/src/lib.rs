@@ -0,0 +1,154 @@
+use std::{cmp::Ordering, convert::Infallible};
+
+use aoc_common::{
+    parse::{blocks, Block, ParseError},
+    Solution,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PacketData {
+    Number(i32),
+    List(Vec<PacketData>),
+}
+
+impl Ord for PacketData {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (PacketData::Number(left), PacketData::Number(right)) => left.cmp(right),
+            (PacketData::Number(_), PacketData::List(right)) => vec![self.clone()].cmp(right),
+            (PacketData::List(left), PacketData::List(right)) => left.cmp(right),
+            (PacketData::List(left), PacketData::Number(_)) => left.cmp(&vec![other.clone()]),
+        }
+    }
+}
+
+impl PartialOrd for PacketData {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn parse_data(src: &str) -> Result<(PacketData, &str), String> {
+    match src.chars().next() {
+        Some('[') => parse_list(&src[1..]),
+        Some(ch) if ch.is_ascii_digit() => parse_number(src),
+        _ => Err(format!("unparsable packet data {:?}", src)),
+    }
+}
+
+fn parse_list(src: &str) -> Result<(PacketData, &str), String> {
+    let mut list: Vec<PacketData> = vec![];
+    let mut remain = src;
+    while let Some(ch) = remain.chars().next() {
+        if ch == ']' {
+            return Ok((PacketData::List(list), &remain[1..]));
+        }
+        let (value, rest) = parse_data(remain)?;
+        list.push(value);
+        remain = rest.trim_start_matches(',').trim_start();
+    }
+    Err("unclosed list".to_string())
+}
+
+fn parse_number(src: &str) -> Result<(PacketData, &str), String> {
+    let end = src
+        .char_indices()
+        .find(|(_, ch)| *ch < '0' || *ch > '9')
+        .map_or(src.len(), |(i, _)| i);
+    let number = src[..end].parse::<i32>().map_err(|err| err.to_string())?;
+    Ok((PacketData::Number(number), &src[end..]))
+}
+
+fn parse_packet(line: &str) -> Result<PacketData, String> {
+    match parse_data(line)? {
+        (packet, "") => Ok(packet),
+        (_, rest) => Err(format!("unexpected {:?} after packet", rest)),
+    }
+}
+
+fn parse_pair(block: Block) -> Result<Pair, ParseError> {
+    let mut packets = block
+        .lines()
+        .map(|line| parse_packet(line.text).map_err(|err| line.error(err)));
+    let mut next = || {
+        packets
+            .next()
+            .unwrap_or_else(|| Err(block.error("expected a pair of packets")))
+    };
+    Ok((next()?, next()?))
+}
+
+pub type Pair = (PacketData, PacketData);
+
+pub fn parse(input: &str) -> Result<Vec<Pair>, ParseError> {
+    blocks(input).map(parse_pair).collect::<Result<Vec<_>, _>>()
+}
+
+pub fn part1(pairs: &[Pair]) -> usize {
+    pairs
+        .iter()
+        .zip(1..)
+        .filter(|((left, right), _)| left.cmp(right) == Ordering::Less)
+        .map(|(_, i)| i)
+        .sum()
+}
+
+pub fn part2(pairs: &[Pair]) -> usize {
+    let dividers = [
+        PacketData::List(vec![PacketData::List(vec![PacketData::Number(2)])]),
+        PacketData::List(vec![PacketData::List(vec![PacketData::Number(6)])]),
+    ];
+
+    let mut packets = pairs
+        .iter()
+        .flat_map(|(left, right)| [left, right])
+        .chain(&dividers)
+        .collect::<Vec<_>>();
+    packets.sort();
+
+    packets
+        .iter()
+        .enumerate()
+        .filter(|(_, packet)| dividers.contains(packet))
+        .map(|(i, _)| i + 1)
+        .product()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Vec<Pair>;
+    type Config = ();
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let pairs = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(13, part1(&pairs));
+    }
+
+    #[test]
+    fn example_part2() {
+        let pairs = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(140, part2(&pairs));
+    }
+}
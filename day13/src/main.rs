@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run::<day13::Day13>(include_str!("input.txt"));
+}
@@ -0,0 +1,198 @@
+use std::convert::Infallible;
+
+use aoc_common::{
+    parse::{parse_lines, ParseError},
+    render::{self, Rgb},
+    visualize, Grid, Point2, Solution,
+};
+
+fn parse_scan_trace(line: &str) -> Result<Vec<Point2>, String> {
+    let trace = line
+        .split(" -> ")
+        .map(|pair| pair.parse::<Point2>().map_err(|err| err.to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+    if let Some(point) = trace.iter().find(|point| point.y < 0) {
+        return Err(format!("{},{} is above the sand source", point.x, point.y));
+    }
+    if let Some([start, end]) = trace
+        .windows(2)
+        .map(|pair| [pair[0], pair[1]])
+        .find(|[start, end]| start.x != end.x && start.y != end.y)
+    {
+        return Err(format!(
+            "diagonal line from {},{} to {},{}",
+            start.x, start.y, end.x, end.y
+        ));
+    }
+    Ok(trace)
+}
+
+fn get_scans(input: &str) -> Result<Vec<Vec<Point2>>, ParseError> {
+    let scans = parse_lines(input, parse_scan_trace).collect::<Result<Vec<_>, _>>()?;
+    if scans.is_empty() {
+        return Err(ParseError::end_of_input(input, "no rock scans"));
+    }
+    Ok(scans)
+}
+
+fn min_max(scans: &[Vec<Point2>]) -> (i32, i32, i32) {
+    let (min, max) = Point2::bounds(scans.iter().flatten().copied()).unwrap();
+    (min.x, max.x, max.y)
+}
+
+fn draw_scans(map: &mut Grid<bool>, scans: &[Vec<Point2>], x_offset: i32) {
+    let offset = Point2::new(x_offset, 0);
+    for path in scans {
+        let mut windows = path.windows(2);
+        while let Some([start, end]) = windows.next() {
+            let step = Point2::new((end.x - start.x).signum(), (end.y - start.y).signum());
+            let mut pos = *start - offset;
+            map[pos] = true;
+            while pos != *end - offset {
+                pos += step;
+                map[pos] = true;
+            }
+        }
+    }
+}
+
+// the rocks and the floor two below the lowest one, wide enough for the sand to pile up to the source
+fn build_map(scans: &[Vec<Point2>]) -> (Grid<bool>, i32) {
+    let (min_x, max_x, max_y) = min_max(scans);
+    let max_y = max_y + 2;
+    let min_x = min_x.min(500 - max_y);
+    let max_x = max_x.max(500 + max_y);
+
+    let mut map = Grid::from_fn((max_x - min_x + 1) as usize, (max_y + 1) as usize, |pos| {
+        pos.y == max_y
+    });
+    draw_scans(&mut map, scans, min_x);
+
+    (map, min_x)
+}
+
+const FALL_OPTIONS: [Point2; 3] = [Point2::new(0, 1), Point2::new(-1, 1), Point2::new(1, 1)];
+
+fn drop_sand(pos: Point2, map: &Grid<bool>) -> Option<Point2> {
+    FALL_OPTIONS
+        .into_iter()
+        .map(|offset| pos + offset)
+        .find(|next| !map[*next])
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<Point2>>, ParseError> {
+    get_scans(input)
+}
+
+fn tile(map: &Grid<bool>, rocks: &Grid<bool>, source: Point2, position: Point2) -> char {
+    match (rocks[position], map[position]) {
+        (true, _) => '#',
+        (false, true) => 'o',
+        _ if position == source => '+',
+        _ => '.',
+    }
+}
+
+fn colour(tile: char) -> Rgb {
+    match tile {
+        '#' => [110, 110, 110],
+        'o' => [230, 190, 90],
+        '+' => [255, 80, 80],
+        _ => [20, 20, 30],
+    }
+}
+
+// the units of sand that come to rest before one falls into the abyss below the rocks,
+// or with a floor, before the source is blocked
+fn pour_sand(scans: &[Vec<Point2>], floor: bool) -> usize {
+    let (_, _, max_y) = min_max(scans);
+    let (mut map, x_offset) = build_map(scans);
+    // only kept to tell the rocks from the sand when drawing
+    let rocks = (visualize::enabled() || render::enabled()).then(|| map.clone());
+    let max = Point2::new(map.width() as i32 - 1, map.height() as i32 - 1);
+
+    let start = Point2::new(500 - x_offset, 0);
+    let mut result = 0;
+
+    'pouring: loop {
+        let mut pos = start;
+
+        while let Some(next) = drop_sand(pos, &map) {
+            if !floor && next.y > max_y {
+                break 'pouring;
+            }
+            pos = next;
+        }
+
+        map[pos] = true;
+        result += 1;
+        if let Some(rocks) = &rocks {
+            let tile = |position| tile(&map, rocks, start, position);
+            visualize::frame(format_args!("{} units of sand", result), || {
+                let (min, max) = visualize::window(Point2::ORIGIN, max, pos);
+                visualize::draw(min, max, tile)
+            });
+            render::frame(|| render::draw(Point2::ORIGIN, max, |position| colour(tile(position))));
+        }
+
+        if pos == start {
+            break;
+        }
+    }
+
+    if let Some(rocks) = &rocks {
+        render::last(|| {
+            render::draw(Point2::ORIGIN, max, |position| {
+                colour(tile(&map, rocks, start, position))
+            })
+        });
+    }
+    result
+}
+
+pub fn part1(scans: &[Vec<Point2>]) -> usize {
+    pour_sand(scans, false)
+}
+
+pub fn part2(scans: &[Vec<Point2>]) -> usize {
+    pour_sand(scans, true)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = Vec<Vec<Point2>>;
+    type Config = ();
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let scans = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(24, part1(&scans));
+    }
+
+    #[test]
+    fn example_part2() {
+        let scans = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(93, part2(&scans));
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run::<day14::Day14>(include_str!("input.txt"));
+}
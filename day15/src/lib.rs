@@ -0,0 +1,164 @@
+use std::{collections::HashSet, ops::RangeInclusive};
+
+use aoc_common::{
+    parallel,
+    parse::{integers, parse_lines, ParseError},
+    NoAnswer, Point2, Solution,
+};
+
+pub struct Sensor {
+    position: Point2,
+    beacon: Point2,
+}
+
+impl Sensor {
+    fn parse(line: &str) -> Result<Sensor, &'static str> {
+        let coordinates = integers(line).collect::<Vec<_>>();
+        let &[x, y, beacon_x, beacon_y] = coordinates.as_slice() else {
+            return Err("expected sensor and beacon coordinates");
+        };
+        let position = Point2::new(x, y);
+        let beacon = Point2::new(beacon_x, beacon_y);
+        Ok(Sensor { position, beacon })
+    }
+
+    fn beacon_distance(&self) -> u32 {
+        self.position.manhattan_distance(self.beacon)
+    }
+
+    fn detection_range(&self, target_row: i32) -> Option<RangeInclusive<i32>> {
+        let row_distance = self.position.y.abs_diff(target_row);
+        let beacon_distance = self.beacon_distance();
+        if row_distance <= beacon_distance {
+            let spread = (beacon_distance - row_distance) as i32;
+            let start = self.position.x - spread;
+            let end = self.position.x + spread;
+            Some(start..=end)
+        } else {
+            None
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    parse_lines(input, Sensor::parse).collect::<Result<Vec<_>, _>>()
+}
+
+pub fn part1(sensors: &[Sensor], target_row: i32) -> usize {
+    let positions = sensors
+        .iter()
+        .filter_map(|sensor| sensor.detection_range(target_row))
+        .flatten()
+        .collect::<HashSet<_>>();
+
+    let beacons = sensors
+        .iter()
+        .filter(|sensor| sensor.beacon.y == target_row)
+        .map(|sensor| sensor.beacon.x)
+        .collect::<HashSet<_>>();
+
+    positions.len() - beacons.len()
+}
+
+// the first column within `columns` of `row` that no sensor covers, skipping to
+// the end of each sensor's range in turn
+fn uncovered(sensors: &[Sensor], row: i32, columns: &RangeInclusive<i32>) -> Option<i32> {
+    let mut column = *columns.start();
+    'columns: while column <= *columns.end() {
+        for range in sensors
+            .iter()
+            .filter_map(|sensor| sensor.detection_range(row))
+        {
+            if range.contains(&column) {
+                if range.end() >= columns.end() {
+                    return None;
+                }
+                column = range.end() + 1;
+                continue 'columns;
+            }
+        }
+        return Some(column);
+    }
+    None
+}
+
+// rows searched one after another by each piece of parallel work
+const ROWS_PER_BLOCK: usize = 1 << 12;
+
+pub fn part2(sensors: &[Sensor], target_range: RangeInclusive<i32>) -> Option<i64> {
+    // the distress beacon is the only position out of every sensor's range; blocks
+    // of rows are searched in parallel
+    let blocks = target_range
+        .clone()
+        .step_by(ROWS_PER_BLOCK)
+        .collect::<Vec<_>>();
+    let pos = parallel::find_map(&blocks, |&first| {
+        let last = first
+            .saturating_add(ROWS_PER_BLOCK as i32 - 1)
+            .min(*target_range.end());
+        (first..=last).find_map(|row| {
+            uncovered(sensors, row, &target_range).map(|column| Point2::new(column, row))
+        })
+    })?;
+
+    Some((pos.x as i64 * 4000000) + pos.y as i64)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Vec<Sensor>;
+    type Config = Config;
+    type Part1 = usize;
+    type Part2 = i64;
+    type Error = NoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        Ok(part1(input, config.target_row))
+    }
+
+    fn part2(input: &Self::Input<'_>, config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        part2(input, config.target_range.clone()).ok_or(NoAnswer)
+    }
+}
+
+pub struct Config {
+    pub target_row: i32,
+    pub target_range: RangeInclusive<i32>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            target_row: 2000000,
+            target_range: 0..=4000000,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let sensors = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(26, part1(&sensors, 10));
+    }
+
+    #[test]
+    fn example_part2() {
+        let sensors = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Some(56000011), part2(&sensors, 0..=20));
+    }
+
+    #[test]
+    fn no_distress_beacon() {
+        let sensors = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(None, part2(&sensors, 8..=12));
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run::<day15::Day15>(include_str!("input.txt"));
+}
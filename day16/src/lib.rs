@@ -0,0 +1,747 @@
+use std::{cmp::Reverse, collections::HashMap, error::Error, fmt::Display};
+
+use aoc_common::{
+    parallel,
+    parse::{lines, ParseError},
+    Solution,
+};
+
+struct Valve {
+    label: String,
+    flow_rate: i32,
+    links: Vec<String>,
+}
+
+impl Valve {
+    fn parse(line: &str) -> Result<Valve, String> {
+        let (valve, links) = line
+            .split_once("; ")
+            .ok_or("expected \"; \" between the valve and its tunnels")?;
+        let (label, flow_rate) = valve
+            .strip_prefix("Valve ")
+            .and_then(|valve| valve.split_once(" has flow rate="))
+            .ok_or("expected \"Valve <label> has flow rate=<rate>\"")?;
+        let links = links
+            .strip_prefix("tunnel leads to valve ")
+            .or_else(|| links.strip_prefix("tunnels lead to valves "))
+            .ok_or("expected a list of tunnels")?;
+        Ok(Valve {
+            label: label.to_string(),
+            flow_rate: flow_rate
+                .parse()
+                .map_err(|err| format!("invalid flow rate: {}", err))?,
+            links: links.split(", ").map(String::from).collect(),
+        })
+    }
+}
+
+// who opens the valves: how many of them, where they all start and the minutes
+// they have
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub agents: usize,
+    pub start: String,
+    pub time: i32,
+}
+
+impl Plan {
+    pub fn new(agents: usize, start: &str, time: i32) -> Plan {
+        Plan {
+            agents,
+            start: start.to_string(),
+            time,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlanError {
+    UnknownValve(String),
+    NoAgents,
+    TooManyValves(usize),
+}
+
+impl Display for PlanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownValve(label) => write!(f, "no valve {}", label),
+            Self::NoAgents => write!(f, "nobody to open the valves"),
+            Self::TooManyValves(count) => write!(
+                f,
+                "{} valves with a flow rate, more than the {} a plan can be made for",
+                count, MAX_USEFUL
+            ),
+        }
+    }
+}
+
+impl Error for PlanError {}
+
+// a valve opened during the given minute, releasing pressure from the next
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Opening {
+    pub valve: String,
+    pub minute: i32,
+}
+
+// what each agent opens and when, and the pressure released by the end of every
+// minute
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    pub agents: Vec<Vec<Opening>>,
+    pub released: Vec<i32>,
+}
+
+impl Schedule {
+    pub fn pressure(&self) -> i32 {
+        self.released.last().copied().unwrap_or(0)
+    }
+
+    // the pressure released during a minute, starting with the first
+    fn flow(&self, minute: usize) -> i32 {
+        self.released[minute - 1] - minute.checked_sub(2).map_or(0, |i| self.released[i])
+    }
+
+    pub fn to_json(&self) -> String {
+        let agents = self
+            .agents
+            .iter()
+            .map(|opened| {
+                let opened = opened
+                    .iter()
+                    .map(|opening| {
+                        format!(
+                            "{{\"valve\":{},\"minute\":{}}}",
+                            json_string(&opening.valve),
+                            opening.minute
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                format!("[{}]", opened.join(","))
+            })
+            .collect::<Vec<_>>();
+        let released = self.released.iter().map(i32::to_string).collect::<Vec<_>>();
+        format!(
+            "{{\"pressure\":{},\"agents\":[{}],\"released\":[{}]}}",
+            self.pressure(),
+            agents.join(","),
+            released.join(",")
+        )
+    }
+}
+
+fn json_string(text: &str) -> String {
+    let mut json = String::from('"');
+    for ch in text.chars() {
+        match ch {
+            '"' | '\\' => {
+                json.push('\\');
+                json.push(ch);
+            }
+            ch if ch.is_control() => json.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => json.push(ch),
+        }
+    }
+    json.push('"');
+    json
+}
+
+// a row per minute with the valves opened during it, the pressure released during
+// it and in all so far
+impl Display for Schedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names = (1..=self.agents.len())
+            .map(|agent| format!("agent {}", agent))
+            .collect::<Vec<_>>();
+        let widths = self
+            .agents
+            .iter()
+            .zip(&names)
+            .map(|(opened, name)| {
+                opened
+                    .iter()
+                    .map(|opening| opening.valve.len())
+                    .chain([name.len()])
+                    .max()
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        write!(f, "minute")?;
+        for (name, width) in names.iter().zip(&widths) {
+            write!(f, "  {:<width$}", name)?;
+        }
+        writeln!(f, "  flow  released")?;
+        for minute in 1..=self.released.len() {
+            write!(f, "{:>6}", minute)?;
+            for (opened, width) in self.agents.iter().zip(&widths) {
+                let valve = opened
+                    .iter()
+                    .find(|opening| opening.minute == minute as i32)
+                    .map_or("", |opening| &opening.valve);
+                write!(f, "  {:<width$}", valve)?;
+            }
+            writeln!(
+                f,
+                "  {:>4}  {:>8}",
+                self.flow(minute),
+                self.released[minute - 1]
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// the valves by their index in the input, with the minutes it takes to walk from
+// each to every other, None when there is no way
+pub struct Solver {
+    labels: Vec<String>,
+    flow_rates: Vec<i32>,
+    distances: Vec<Vec<Option<i32>>>,
+    // the valves worth opening, highest flow rate first, which give the bits of
+    // the sets of valves below
+    useful: Vec<usize>,
+}
+
+const TIME_AVAILABLE: i32 = 30;
+// four minutes go on teaching the elephant
+const TIME_WITH_ELEPHANT: i32 = 26;
+const START: &str = "AA";
+// the plan keeps the best pressure for each of the 2^n sets of the useful valves,
+// several tables of them with more agents, which takes too much memory beyond this
+const MAX_USEFUL: usize = 24;
+
+// Floyd–Warshall, from the tunnels between neighbouring valves
+fn all_distances(links: &[Vec<usize>]) -> Vec<Vec<Option<i32>>> {
+    let mut distances = vec![vec![None; links.len()]; links.len()];
+    for (from, links) in links.iter().enumerate() {
+        distances[from][from] = Some(0);
+        for &to in links {
+            distances[from][to] = Some(1);
+        }
+    }
+    for via in 0..links.len() {
+        let through = distances[via].clone();
+        for row in distances.iter_mut() {
+            let Some(first) = row[via] else {
+                continue;
+            };
+            for (known, second) in row.iter_mut().zip(&through) {
+                if let Some(second) = second {
+                    let distance = first + second;
+                    if known.is_none_or(|known| distance < known) {
+                        *known = Some(distance);
+                    }
+                }
+            }
+        }
+    }
+    distances
+}
+
+// the best one agent does opening exactly a set of valves, and the best any set
+// within a larger one does, with that set
+#[derive(Clone, Copy)]
+struct Best {
+    pressure: i32,
+    set: u32,
+}
+
+// the best split of `set` between one more agent and the others, who do `fewer`
+// within each set; the set is the one the agent opens within
+fn split(within: &[Best], fewer: &[Best], set: usize) -> Best {
+    let mut best = Best {
+        pressure: -1,
+        set: 0,
+    };
+    // every subset of the set, down to the empty one
+    let mut mine = set;
+    loop {
+        let pressure = within[mine].pressure + fewer[set ^ mine].pressure;
+        if pressure > best.pressure {
+            best = Best {
+                pressure,
+                set: mine as u32,
+            };
+        }
+        if mine == 0 {
+            return best;
+        }
+        mine = (mine - 1) & set;
+    }
+}
+
+impl Solver {
+    fn parse(input: &str) -> Result<Solver, ParseError> {
+        let valves = lines(input)
+            .map(|line| Valve::parse(line.text).map_err(|err| line.error(err)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let indices = valves
+            .iter()
+            .enumerate()
+            .map(|(i, valve)| (valve.label.as_str(), i))
+            .collect::<HashMap<_, _>>();
+        let mut links = vec![];
+        for (line, valve) in lines(input).zip(&valves) {
+            links.push(
+                valve
+                    .links
+                    .iter()
+                    .map(|link| {
+                        indices
+                            .get(link.as_str())
+                            .copied()
+                            .ok_or_else(|| line.error(format!("tunnel to unknown valve {}", link)))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?,
+            );
+        }
+
+        let mut useful = (0..valves.len())
+            .filter(|&i| valves[i].flow_rate > 0)
+            .collect::<Vec<_>>();
+        useful.sort_by_key(|&i| Reverse(valves[i].flow_rate));
+
+        Ok(Solver {
+            distances: all_distances(&links),
+            flow_rates: valves.iter().map(|valve| valve.flow_rate).collect(),
+            labels: valves.into_iter().map(|valve| valve.label).collect(),
+            useful,
+        })
+    }
+
+    fn index(&self, label: &str) -> Option<usize> {
+        self.labels.iter().position(|l| l == label)
+    }
+
+    // walks every order of opening the useful valves from `valve`, noting the most
+    // pressure released for each set of them opened
+    fn visit(
+        &self,
+        valve: usize,
+        minute: i32,
+        set: u32,
+        pressure: i32,
+        time: i32,
+        best: &mut [i32],
+    ) {
+        best[set as usize] = best[set as usize].max(pressure);
+        for (bit, &next) in self.useful.iter().enumerate() {
+            if set & 1 << bit != 0 {
+                continue;
+            }
+            let Some(distance) = self.distances[valve][next] else {
+                continue;
+            };
+            let open = minute + distance + 1;
+            if open < time {
+                let pressure = pressure + self.flow_rates[next] * (time - open);
+                self.visit(next, open, set | 1 << bit, pressure, time, best);
+            }
+        }
+    }
+
+    // an order to open exactly `set` in releasing the given pressure, as the
+    // valves and the minutes they are opened in
+    fn route(
+        &self,
+        valve: usize,
+        minute: i32,
+        set: u32,
+        pressure: i32,
+        time: i32,
+    ) -> Option<Vec<(usize, i32)>> {
+        if set == 0 {
+            return (pressure == 0).then(Vec::new);
+        }
+        for (bit, &next) in self.useful.iter().enumerate() {
+            if set & 1 << bit == 0 {
+                continue;
+            }
+            let Some(distance) = self.distances[valve][next] else {
+                continue;
+            };
+            let open = minute + distance + 1;
+            let released = self.flow_rates[next] * (time - open);
+            if open >= time || released > pressure {
+                continue;
+            }
+            let rest = set & !(1 << bit);
+            if let Some(mut route) = self.route(next, open, rest, pressure - released, time) {
+                route.insert(0, (next, open));
+                return Some(route);
+            }
+        }
+        None
+    }
+
+    // the most pressure the agents can release together: the best one agent does
+    // with each set of valves, and then the best split of the valves between them,
+    // each opening a set the others leave alone
+    pub fn schedule(&self, plan: &Plan) -> Result<Schedule, PlanError> {
+        let start = self
+            .index(&plan.start)
+            .ok_or_else(|| PlanError::UnknownValve(plan.start.clone()))?;
+        if plan.agents == 0 {
+            return Err(PlanError::NoAgents);
+        }
+        if self.useful.len() > MAX_USEFUL {
+            return Err(PlanError::TooManyValves(self.useful.len()));
+        }
+
+        let sets = 1 << self.useful.len();
+        let mut exact = vec![-1; sets];
+        self.visit(start, 0, 0, 0, plan.time, &mut exact);
+        let mut within = exact
+            .iter()
+            .zip(0..)
+            .map(|(&pressure, set)| Best { pressure, set })
+            .collect::<Vec<_>>();
+        for set in 0..sets {
+            for bit in 0..self.useful.len() {
+                if set & 1 << bit != 0 && within[set ^ 1 << bit].pressure > within[set].pressure {
+                    within[set] = within[set ^ 1 << bit];
+                }
+            }
+        }
+
+        // for each number of agents short of all of them, the best they do within
+        // every set of valves; all of them only need the best split of everything
+        let everything = sets - 1;
+        let mut teams = vec![within.clone()];
+        for _ in 2..plan.agents {
+            let fewer = teams.last().unwrap();
+            let team = parallel::map_indices(sets, |set| split(&within, fewer, set));
+            teams.push(team);
+        }
+
+        let mut rest = everything;
+        let mut sets = vec![];
+        if plan.agents > 1 {
+            let mine = split(&within, teams.last().unwrap(), everything).set as usize;
+            sets.push(within[mine].set);
+            rest ^= mine;
+        }
+        for team in teams[1..].iter().rev() {
+            let mine = team[rest].set as usize;
+            sets.push(within[mine].set);
+            rest ^= mine;
+        }
+        sets.push(within[rest].set);
+        sets.reverse();
+
+        let mut routes = sets
+            .into_iter()
+            .map(|set| {
+                self.route(start, 0, set, exact[set as usize], plan.time)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        // in the order the agents open their first valves
+        routes.sort_by_key(|route| route.first().map_or(i32::MAX, |&(_, minute)| minute));
+        let released = (1..=plan.time)
+            .scan(0, |released, minute| {
+                *released += routes
+                    .iter()
+                    .flatten()
+                    .filter(|(_, open)| *open < minute)
+                    .map(|&(valve, _)| self.flow_rates[valve])
+                    .sum::<i32>();
+                Some(*released)
+            })
+            .collect();
+        let agents = routes
+            .into_iter()
+            .map(|route| {
+                route
+                    .into_iter()
+                    .map(|(valve, minute)| Opening {
+                        valve: self.labels[valve].clone(),
+                        minute,
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(Schedule { agents, released })
+    }
+
+    pub fn max_pressure(&self, plan: &Plan) -> Result<i32, PlanError> {
+        Ok(self.schedule(plan)?.pressure())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Solver, ParseError> {
+    Solver::parse(input)
+}
+
+pub struct Config {
+    pub alone: Plan,
+    pub with_elephant: Plan,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            alone: Plan::new(1, START, TIME_AVAILABLE),
+            with_elephant: Plan::new(2, START, TIME_WITH_ELEPHANT),
+        }
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Solver;
+    type Config = Config;
+    type Part1 = i32;
+    type Part2 = i32;
+    type Error = PlanError;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        input.max_pressure(&config.alone)
+    }
+
+    fn part2(input: &Self::Input<'_>, config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        input.max_pressure(&config.with_elephant)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cmp::Ordering, collections::BinaryHeap};
+
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let solver = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Ok(1651), solver.max_pressure(&Config::default().alone));
+    }
+
+    #[test]
+    fn example_part2() {
+        let solver = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(
+            Ok(1707),
+            solver.max_pressure(&Config::default().with_elephant)
+        );
+    }
+
+    // the best-first search the planner replaced, kept to check it: the agent free
+    // first picks its next valve or stops, counting what the valve releases from
+    // the moment it heads for it, and the states that cannot beat the best so far
+    // even opening the remaining valves as close together as any two are dropped
+    #[derive(Clone, PartialEq, Eq)]
+    struct State {
+        pressure: i32,
+        potential: i32,
+        agents: Vec<(usize, i32)>,
+        inactive: Vec<usize>,
+    }
+
+    impl Ord for State {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.pressure
+                .cmp(&other.pressure)
+                .then_with(|| self.potential.cmp(&other.potential))
+        }
+    }
+
+    impl PartialOrd for State {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    fn search(solver: &Solver, plan: &Plan) -> i32 {
+        let start = solver.index(&plan.start).unwrap();
+        let distance = |from: usize, to: usize| solver.distances[from][to];
+        let inactive = solver
+            .useful
+            .iter()
+            .copied()
+            .filter(|&valve| distance(start, valve).is_some())
+            .collect::<Vec<_>>();
+        let step = inactive
+            .iter()
+            .flat_map(|&a| {
+                let others = inactive.iter().filter(move |&&b| b != a);
+                others.map(move |&b| distance(a, b).unwrap())
+            })
+            .chain(
+                inactive
+                    .iter()
+                    .map(|&valve| distance(start, valve).unwrap()),
+            )
+            .min()
+            .unwrap_or(0);
+        let potential = |state: &State| {
+            let working = state
+                .agents
+                .iter()
+                .filter(|(_, free_at)| *free_at < plan.time);
+            let agents = working.clone().count();
+            let Some(minute) = working.map(|(_, free_at)| *free_at).min() else {
+                return 0;
+            };
+            let released = state.inactive.iter().enumerate().map(|(i, &valve)| {
+                let open = minute + (step + 1) * ((i / agents) as i32 + 1);
+                solver.flow_rates[valve] * (plan.time - open).max(0)
+            });
+            released.sum()
+        };
+
+        let mut best = 0;
+        let mut heap = BinaryHeap::from([State {
+            pressure: 0,
+            potential: i32::MAX,
+            agents: vec![(start, 0); plan.agents],
+            inactive,
+        }]);
+        while let Some(state) = heap.pop() {
+            if state.pressure.saturating_add(state.potential) <= best {
+                continue;
+            }
+            let (i, &(valve, free_at)) = state
+                .agents
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, (_, free_at))| *free_at)
+                .unwrap();
+            let mut next = vec![];
+            for &target in &state.inactive {
+                let open = free_at + distance(valve, target).unwrap() + 1;
+                if open < plan.time {
+                    let mut state = state.clone();
+                    state.pressure += solver.flow_rates[target] * (plan.time - open);
+                    state.agents[i] = (target, open);
+                    state.inactive.retain(|&v| v != target);
+                    next.push(state);
+                }
+            }
+            if state.agents.len() > 1 && free_at < plan.time {
+                let mut state = state.clone();
+                state.agents[i].1 = plan.time;
+                next.push(state);
+            }
+            for mut state in next {
+                state.potential = potential(&state);
+                best = best.max(state.pressure);
+                if state.pressure + state.potential > best {
+                    heap.push(state);
+                }
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn other_plans() {
+        let solver = parse(include_str!("example.txt")).unwrap();
+        for plan in [
+            Plan::new(1, "AA", 1),
+            Plan::new(1, "DD", 30),
+            Plan::new(2, "JJ", 20),
+            Plan::new(3, "AA", 26),
+            Plan::new(3, "HH", 12),
+        ] {
+            assert_eq!(Ok(search(&solver, &plan)), solver.max_pressure(&plan));
+        }
+        assert_eq!(Ok(1794), solver.max_pressure(&Plan::new(3, "AA", 26)));
+        assert_eq!(
+            Err(PlanError::UnknownValve("ZZ".to_string())),
+            solver.max_pressure(&Plan::new(1, "ZZ", 30))
+        );
+        assert_eq!(
+            Err(PlanError::NoAgents),
+            solver.max_pressure(&Plan::new(0, "AA", 30))
+        );
+    }
+
+    #[test]
+    fn too_many_valves() {
+        let names: Vec<String> = (0..25).map(|i| format!("V{}", i)).collect();
+        let mut input = format!(
+            "Valve AA has flow rate=0; tunnels lead to valves {}\n",
+            names.join(", ")
+        );
+        for name in &names {
+            input += &format!("Valve {} has flow rate=1; tunnel leads to valve AA\n", name);
+        }
+        let solver = parse(&input).unwrap();
+        assert_eq!(
+            Err(PlanError::TooManyValves(25)),
+            solver.max_pressure(&Plan::new(1, "AA", 30))
+        );
+    }
+
+    #[test]
+    fn puzzle_plans() {
+        let solver = parse(include_str!("input.txt")).unwrap();
+        let config = Config::default();
+        for plan in [
+            config.alone,
+            config.with_elephant,
+            Plan::new(1, "AA", 20),
+            Plan::new(2, "AA", 18),
+            Plan::new(3, "AA", 14),
+        ] {
+            assert_eq!(Ok(search(&solver, &plan)), solver.max_pressure(&plan));
+        }
+    }
+
+    #[test]
+    fn schedules() {
+        let solver = parse(include_str!("example.txt")).unwrap();
+        let opening = |valve: &str, minute| Opening {
+            valve: valve.to_string(),
+            minute,
+        };
+        let schedule = solver.schedule(&Config::default().alone).unwrap();
+        assert_eq!(
+            vec![vec![
+                opening("DD", 2),
+                opening("BB", 5),
+                opening("JJ", 9),
+                opening("HH", 17),
+                opening("EE", 21),
+                opening("CC", 24)
+            ]],
+            schedule.agents
+        );
+        assert_eq!(&[0, 0, 20, 40, 60, 93], &schedule.released[..6]);
+        assert_eq!(
+            "minute  agent 1  flow  released\n\
+             \x20    1              0         0\n\
+             \x20    2  DD          0         0\n\
+             \x20    3             20        20\n",
+            schedule
+                .to_string()
+                .split_inclusive('\n')
+                .take(4)
+                .collect::<String>()
+        );
+
+        let schedule = solver.schedule(&Plan::new(2, "AA", 6)).unwrap();
+        assert_eq!(
+            "{\"pressure\":156,\"agents\":[[{\"valve\":\"DD\",\"minute\":2},\
+             {\"valve\":\"BB\",\"minute\":5}],[{\"valve\":\"JJ\",\"minute\":3}]],\
+             \"released\":[0,0,20,61,102,156]}",
+            schedule.to_json()
+        );
+        assert_eq!("\"a\\\"b\\\\\\u0007\"", json_string("a\"b\\\x07"));
+    }
+
+    #[test]
+    fn malformed_input() {
+        let input = include_str!("example.txt").replacen("rate=13", "rate=x", 1);
+        let err = parse(&input).err().unwrap();
+        assert_eq!(2, err.line());
+        assert!(err.reason().starts_with("invalid flow rate"));
+    }
+}
@@ -0,0 +1,59 @@
+use std::env;
+
+use aoc_common::{input, take_options, ArgumentError, OrExit};
+use day16::{parse, Config, Day16, Plan};
+
+const PLAN_OPTIONS: [&str; 3] = ["--agents", "--start", "--time"];
+
+// takes --agents <N>, --start <VALVE> and --time <MINUTES> out of the arguments,
+// starting from the plan for the first part
+fn plan_from_args(args: Vec<String>) -> Result<(Plan, Vec<String>), ArgumentError> {
+    let mut plan = Config::default().alone;
+    let mut rest = vec![];
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if !PLAN_OPTIONS.contains(&arg.as_str()) {
+            rest.push(arg);
+            continue;
+        }
+        let value = args
+            .next()
+            .ok_or_else(|| ArgumentError::MissingValue(arg.clone()))?;
+        let invalid = || ArgumentError::InvalidValue(arg.clone(), value.clone());
+        match arg.as_str() {
+            "--agents" => plan.agents = value.parse().map_err(|_| invalid())?,
+            "--start" => plan.start = value,
+            _ => plan.time = value.parse().map_err(|_| invalid())?,
+        }
+    }
+    Ok((plan, rest))
+}
+
+// the answers follow the puzzle's own plans
+fn reject_plan_options(args: &[String]) -> Result<(), ArgumentError> {
+    match args.iter().find(|arg| PLAN_OPTIONS.contains(&arg.as_str())) {
+        Some(option) => Err(ArgumentError::Unexpected(option.clone())),
+        None => Ok(()),
+    }
+}
+
+// `schedule` prints which valve each agent opens when and the pressure released
+// every minute, as a table or with --json as JSON, instead of the answers
+fn main() {
+    let mut args = env::args().skip(1).peekable();
+    if args.peek().map(String::as_str) != Some("schedule") {
+        let args = take_options(args);
+        reject_plan_options(&args).or_exit();
+        return aoc_common::run_with::<Day16>(args, include_str!("input.txt"));
+    }
+    let args = take_options(args.skip(1));
+    let json = args.iter().any(|arg| arg == "--json");
+    let args = args.into_iter().filter(|arg| arg != "--json").collect();
+    let (plan, args) = plan_from_args(args).or_exit();
+    let input = input::from_args(args.into_iter(), include_str!("input.txt")).or_exit();
+    let schedule = parse(&input).or_exit().schedule(&plan).or_exit();
+    match json {
+        true => println!("{}", schedule.to_json()),
+        false => print!("{}", schedule),
+    }
+}
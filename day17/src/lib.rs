@@ -1,10 +1,18 @@
-use std::{cell::Cell, cmp::Ordering};
+use std::{cell::Cell, cmp::Ordering, convert::Infallible};
+
+use aoc_common::{
+    parse::{lines, ParseError},
+    render::{self, Image, Rgb},
+    visualize, Solution,
+};
 
 const CHAMBER_WIDTH: usize = 7;
-const NUMBER_OF_ROCKS: usize = 1_000_000_000_000;
+const NUMBER_OF_ROCKS: usize = 2022;
+const NUMBER_OF_ROCKS_FOR_ELEPHANTS: usize = 1_000_000_000_000;
 const SAMPLE_SIZE: usize = 10_000;
 const REPEAT_TEST_WINDOW: usize = 100;
 const EMPTY_ROW: [char; CHAMBER_WIDTH] = [' '; CHAMBER_WIDTH];
+const RENDER_ROWS: usize = 100;
 
 fn get_rocks() -> [Vec<&'static str>; 5] {
     [
@@ -65,7 +73,7 @@ fn drop_rock(
         match jet {
             '>' => next_pos.0 = (CHAMBER_WIDTH - rock[0].len()).min(next_pos.0 + 1),
             '<' => next_pos.0 = if pos.0 > 0 { pos.0 - 1 } else { pos.0 },
-            _ => panic!("Unknown jet type: {}", jet),
+            _ => unreachable!("jets are checked by parse"),
         }
 
         // If jet movement causes overlap then reset next pos, otherwise set pos to next pos
@@ -101,6 +109,30 @@ fn add_rock(pos: &(usize, usize), rock: &[&'static str], chamber: &mut [[char; C
     })
 }
 
+// the top rows of the chamber, and its floor once that comes into view
+fn draw_chamber(chamber: &[[char; CHAMBER_WIDTH]], rows: usize) -> String {
+    let mut text = String::new();
+    for row in chamber.iter().rev().take(rows) {
+        text.push('|');
+        text.extend(row.iter().map(|&ch| if ch == '#' { '#' } else { '.' }));
+        text.push_str("|\n");
+    }
+    if chamber.len() <= rows {
+        text.push('+');
+        text.extend(['-'; CHAMBER_WIDTH]);
+        text.push_str("+\n");
+    }
+    text
+}
+
+fn colour(tile: char) -> Rgb {
+    match tile {
+        '#' => [200, 120, 60],
+        '.' => [20, 20, 30],
+        _ => [110, 110, 110],
+    }
+}
+
 fn calculate_final_height(end_states: &[(usize, usize, usize)]) -> Option<usize> {
     let target = end_states
         .iter()
@@ -124,27 +156,60 @@ fn calculate_final_height(end_states: &[(usize, usize, usize)]) -> Option<usize>
 
     if samples.len() > 1 {
         let repeat_rock_count = samples[1].0 - samples[0].0;
-        let remaining_repeats = ((NUMBER_OF_ROCKS - SAMPLE_SIZE) / repeat_rock_count) + 1;
-        let index = NUMBER_OF_ROCKS - (remaining_repeats * repeat_rock_count) - 1;
+        let remaining_repeats =
+            ((NUMBER_OF_ROCKS_FOR_ELEPHANTS - SAMPLE_SIZE) / repeat_rock_count) + 1;
+        let index = NUMBER_OF_ROCKS_FOR_ELEPHANTS - (remaining_repeats * repeat_rock_count) - 1;
         let height_before_repeats = end_states[index].2;
         let repeat_height = height_before_repeats - end_states[index - repeat_rock_count].2;
 
-        if cfg!(debug_assertions) {
-            println!("repeat rock count => {}", repeat_rock_count);
-            println!("remaining repeats => {}", remaining_repeats);
-            println!("height before repeats => {}", height_before_repeats);
-            println!("repeat height => {}", repeat_height);
-            println!();
-        }
-
         Some(height_before_repeats + (remaining_repeats * repeat_height))
     } else {
         None
     }
 }
 
-fn solve(input: &str) -> usize {
-    let jets = input.trim_end();
+pub fn parse(input: &str) -> Result<&str, ParseError> {
+    let line = lines(input)
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, "missing jet pattern"))?;
+    match line
+        .text
+        .char_indices()
+        .find(|(_, ch)| !matches!(ch, '<' | '>'))
+    {
+        Some((i, ch)) => Err(line.error(format!("unknown jet {:?} at column {}", ch, i + 1))),
+        None if line.text.is_empty() => Err(line.error("empty jet pattern")),
+        None => Ok(line.text),
+    }
+}
+
+pub fn part1(jets: &str) -> usize {
+    let mut jets_iter = jets.chars().cycle();
+    let mut get_jet = || jets_iter.next().unwrap();
+    let mut chamber: Vec<[char; CHAMBER_WIDTH]> = vec![];
+
+    for (i, rock) in get_rocks()
+        .into_iter()
+        .cycle()
+        .take(NUMBER_OF_ROCKS)
+        .enumerate()
+    {
+        update_chamber_height(&mut chamber, rock.len());
+        let pos = drop_rock(&rock, &mut get_jet, &chamber);
+        add_rock(&pos, &rock, &mut chamber);
+        visualize::frame(format_args!("Rock {}", i + 1), || {
+            draw_chamber(&chamber, visualize::MAX_HEIGHT as usize - 1)
+        });
+        render::frame(|| Image::from_text(&draw_chamber(&chamber, RENDER_ROWS), colour));
+    }
+    render::last(|| Image::from_text(&draw_chamber(&chamber, RENDER_ROWS), colour));
+
+    chamber.len() - empty_space(&chamber)
+}
+
+// drops rocks in samples until the rock and jet positions start repeating, then
+// extrapolates the height from one repeat
+pub fn part2(jets: &str) -> usize {
     let mut jets_iter = jets.chars().cycle();
     let mut chamber: Vec<[char; CHAMBER_WIDTH]> = vec![];
 
@@ -178,9 +243,26 @@ fn solve(input: &str) -> usize {
     }
 }
 
-fn main() {
-    let result = solve(include_str!("input.txt"));
-    println!("{}", result);
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = &'a str;
+    type Config = ();
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        Ok(part2(input))
+    }
 }
 
 #[cfg(test)]
@@ -188,14 +270,14 @@ mod tests {
     use super::*;
 
     #[test]
-    fn example_result() {
-        let result = solve(include_str!("example.txt"));
-        assert_eq!(1514285714288, result);
+    fn example_part1() {
+        let jets = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(3068, part1(jets));
     }
 
     #[test]
-    fn puzzle_result() {
-        let result = solve(include_str!("input.txt"));
-        assert_eq!(1570930232582, result);
+    fn example_part2() {
+        let jets = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(1514285714288, part2(jets));
     }
 }
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run::<day17::Day17>(include_str!("input.txt"));
+}
@@ -0,0 +1,97 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    convert::Infallible,
+};
+
+use aoc_common::{
+    parse::{lines, ParseError},
+    Point3, Solution,
+};
+
+fn generate_steam(cubes: &HashSet<Point3>) -> HashSet<Point3> {
+    let Some((min, max)) = Point3::bounds(cubes.iter().copied()) else {
+        return HashSet::new();
+    };
+    let min = min - Point3::new(1, 1, 1);
+    let max = max + Point3::new(1, 1, 1);
+    let in_bounds = |pos: &Point3| {
+        (min.x..=max.x).contains(&pos.x)
+            && (min.y..=max.y).contains(&pos.y)
+            && (min.z..=max.z).contains(&pos.z)
+    };
+
+    let mut result = HashSet::new();
+    let mut candidates = VecDeque::from([min]);
+
+    while let Some(pos) = candidates.pop_front() {
+        if !cubes.contains(&pos) && !result.contains(&pos) {
+            candidates.extend(pos.neighbours6().filter(in_bounds));
+            result.insert(pos);
+        }
+    }
+
+    result
+}
+
+// the sides of `cube` facing a position that `is_open`
+fn get_exposed_sides(cube: &Point3, is_open: impl Fn(&Point3) -> bool) -> usize {
+    cube.neighbours6().filter(is_open).count()
+}
+
+pub fn parse(input: &str) -> Result<HashSet<Point3>, ParseError> {
+    lines(input).map(|line| line.parse::<Point3>()).collect()
+}
+
+pub fn part1(cubes: &HashSet<Point3>) -> usize {
+    cubes
+        .iter()
+        .map(|cube| get_exposed_sides(cube, |pos| !cubes.contains(pos)))
+        .sum()
+}
+
+pub fn part2(cubes: &HashSet<Point3>) -> usize {
+    let steam = generate_steam(cubes);
+    cubes
+        .iter()
+        .map(|cube| get_exposed_sides(cube, |pos| steam.contains(pos)))
+        .sum()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = HashSet<Point3>;
+    type Config = ();
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let cubes = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(64, part1(&cubes));
+    }
+
+    #[test]
+    fn example_part2() {
+        let cubes = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(58, part2(&cubes));
+    }
+}
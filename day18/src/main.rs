@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run::<day18::Day18>(include_str!("input.txt"));
+}
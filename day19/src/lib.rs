@@ -0,0 +1,549 @@
+use std::{error::Error, fmt::Display, num::ParseIntError, str::FromStr};
+
+use aoc_common::{
+    parallel,
+    parse::{lines, ParseError},
+    Solution,
+};
+
+const TIME_LIMIT: i32 = 24;
+// the elephants eat all but three of the blueprints but leave more time
+const HUNGRY_ELEPHANTS_TIME_LIMIT: i32 = 32;
+
+// a robot collecting one of a resource a minute, and what it costs of each
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Recipe {
+    robot: usize,
+    costs: Vec<i32>,
+}
+
+// the resources are numbered in the order the blueprint first mentions them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Blueprint {
+    id: i32,
+    resources: Vec<String>,
+    recipes: Vec<Recipe>,
+}
+
+impl Blueprint {
+    pub fn id(&self) -> i32 {
+        self.id
+    }
+
+    pub fn resources(&self) -> &[String] {
+        &self.resources
+    }
+
+    fn resource(&self, name: &str) -> Result<usize, BlueprintError> {
+        self.resources
+            .iter()
+            .position(|resource| resource == name)
+            .ok_or_else(|| BlueprintError::UnknownResource {
+                blueprint: self.id,
+                resource: name.to_string(),
+            })
+    }
+}
+
+fn resource_id(resources: &mut Vec<String>, name: &str) -> usize {
+    match resources.iter().position(|resource| resource == name) {
+        Some(id) => id,
+        None => {
+            resources.push(name.to_string());
+            resources.len() - 1
+        }
+    }
+}
+
+fn parse_costs(costs: &str, resources: &mut Vec<String>) -> Result<Vec<(usize, i32)>, String> {
+    let mut result = vec![];
+    for resource in costs.split(" and ") {
+        let (number, res_name) = resource
+            .split_once(' ')
+            .ok_or_else(|| format!("expected an amount and a resource in {:?}", resource))?;
+        let number = number
+            .parse()
+            .map_err(|err: ParseIntError| err.to_string())?;
+        result.push((resource_id(resources, res_name), number));
+    }
+    Ok(result)
+}
+
+impl FromStr for Blueprint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (bp, rest) = s
+            .split_once(": Each ")
+            .ok_or("expected \"Blueprint <id>: Each ...\"")?;
+        let id = bp
+            .trim_start_matches("Blueprint ")
+            .parse()
+            .map_err(|err: ParseIntError| format!("invalid blueprint id: {}", err))?;
+        let mut resources = vec![];
+        let mut recipes = vec![];
+        for robot in rest.split(" Each ") {
+            let (res_name, costs) = robot
+                .split_once(" robot costs ")
+                .ok_or_else(|| format!("expected a robot recipe in {:?}", robot))?;
+            let robot = resource_id(&mut resources, res_name);
+            if recipes.iter().any(|recipe: &(usize, _)| recipe.0 == robot) {
+                return Err(format!("a second recipe for {} robots", res_name));
+            }
+            recipes.push((
+                robot,
+                parse_costs(costs.trim_end_matches('.'), &mut resources)?,
+            ));
+        }
+        let recipes = recipes
+            .into_iter()
+            .map(|(robot, costs)| {
+                let mut all_costs = vec![0; resources.len()];
+                for (resource, cost) in costs {
+                    all_costs[resource] += cost;
+                }
+                Recipe {
+                    robot,
+                    costs: all_costs,
+                }
+            })
+            .collect();
+        Ok(Blueprint {
+            id,
+            resources,
+            recipes,
+        })
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+    lines(input).map(|line| line.parse()).collect()
+}
+
+// what the factory is after: as much of one resource as it can collect in the
+// time, starting out with a single robot
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Goal {
+    pub objective: String,
+    pub first_robot: String,
+    pub time_limit: i32,
+}
+
+impl Default for Goal {
+    fn default() -> Self {
+        Goal {
+            objective: "geode".to_string(),
+            first_robot: "ore".to_string(),
+            time_limit: TIME_LIMIT,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlueprintError {
+    UnknownResource { blueprint: i32, resource: String },
+}
+
+impl Display for BlueprintError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownResource {
+                blueprint,
+                resource,
+            } => write!(f, "blueprint {} has no {}", blueprint, resource),
+        }
+    }
+}
+
+impl Error for BlueprintError {}
+
+// a minute of a build plan: the robot being built, ready at the end of it, and the
+// robots and resources there are then
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Minute {
+    pub build: Option<String>,
+    pub robots: Vec<i32>,
+    pub resources: Vec<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildPlan {
+    pub resources: Vec<String>,
+    pub objective: usize,
+    pub minutes: Vec<Minute>,
+}
+
+impl BuildPlan {
+    pub fn collected(&self) -> i32 {
+        self.minutes
+            .last()
+            .map_or(0, |minute| minute.resources[self.objective])
+    }
+
+    // the robots built and the minutes they are built in
+    pub fn builds(&self) -> Vec<(i32, &str)> {
+        (1..)
+            .zip(&self.minutes)
+            .filter_map(|(minute, step)| Some((minute, step.build.as_deref()?)))
+            .collect()
+    }
+}
+
+// a row per minute with the robot built in it and then, for each resource, the
+// amount held at the end of the minute and the robots collecting it in brackets
+impl Display for BuildPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let cells = self
+            .minutes
+            .iter()
+            .map(|minute| {
+                let resources = minute.resources.iter().zip(&minute.robots);
+                resources
+                    .map(|(amount, robots)| format!("{} ({})", amount, robots))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let build_width = self
+            .resources
+            .iter()
+            .map(String::len)
+            .chain(["build".len()])
+            .max()
+            .unwrap();
+        let widths = self
+            .resources
+            .iter()
+            .enumerate()
+            .map(|(i, resource)| {
+                let cells = cells.iter().map(|row| row[i].len());
+                cells.chain([resource.len()]).max().unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        write!(f, "minute  {:<build_width$}", "build")?;
+        for (resource, width) in self.resources.iter().zip(&widths) {
+            write!(f, "  {:>width$}", resource)?;
+        }
+        writeln!(f)?;
+        for (minute, (step, row)) in self.minutes.iter().zip(&cells).enumerate() {
+            let build = step.build.as_deref().unwrap_or("");
+            write!(f, "{:>6}  {:<build_width$}", minute + 1, build)?;
+            for (cell, width) in row.iter().zip(&widths) {
+                write!(f, "  {:>width$}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+// a depth first search over the robot to build next, waiting for the resources it
+// needs as long as it takes
+struct Search<'a> {
+    blueprint: &'a Blueprint,
+    objective: usize,
+    time_limit: i32,
+    // the robots collecting the objective first, then the others from the last
+    // listed, which tend to be the ones nearer it
+    order: Vec<usize>,
+    // more robots than a recipe spends in a minute collect more than a robot a
+    // minute can use, except for the objective
+    limits: Vec<i32>,
+    builds: Vec<(i32, usize)>,
+    best: i32,
+    best_builds: Vec<(i32, usize)>,
+}
+
+impl Search<'_> {
+    fn visit(&mut self, minute: i32, robots: &[i32], resources: &[i32]) {
+        let remaining = self.time_limit - minute;
+        let collected = resources[self.objective] + robots[self.objective] * remaining;
+        if collected > self.best {
+            self.best = collected;
+            self.best_builds = self.builds.clone();
+        }
+        // even with another robot collecting the objective every minute left
+        if collected + remaining * (remaining - 1) / 2 <= self.best {
+            return;
+        }
+
+        for i in 0..self.order.len() {
+            let recipe = &self.blueprint.recipes[self.order[i]];
+            if robots[recipe.robot] >= self.limits[recipe.robot] {
+                continue;
+            }
+            let mut wait = 0;
+            let affordable = recipe.costs.iter().enumerate().all(|(resource, &cost)| {
+                let missing = cost - resources[resource];
+                if missing <= 0 {
+                    return true;
+                }
+                let collecting = robots[resource];
+                if collecting > 0 {
+                    wait = wait.max((missing + collecting - 1) / collecting);
+                }
+                collecting > 0
+            });
+            let built = minute + wait + 1;
+            if !affordable || built >= self.time_limit {
+                continue;
+            }
+
+            let resources = (0..resources.len())
+                .map(|r| resources[r] + robots[r] * (wait + 1) - recipe.costs[r])
+                .collect::<Vec<_>>();
+            let mut robots = robots.to_vec();
+            robots[recipe.robot] += 1;
+            self.builds.push((built, self.order[i]));
+            self.visit(built, &robots, &resources);
+            self.builds.pop();
+        }
+    }
+}
+
+// the most of the objective the blueprint's robots can collect in time, with the
+// robots to build minute by minute
+pub fn best_plan(bp: &Blueprint, goal: &Goal) -> Result<BuildPlan, BlueprintError> {
+    let objective = bp.resource(&goal.objective)?;
+    let first_robot = bp.resource(&goal.first_robot)?;
+
+    let mut order = (0..bp.recipes.len())
+        .filter(|&i| bp.recipes[i].robot == objective)
+        .collect::<Vec<_>>();
+    order.extend(
+        (0..bp.recipes.len())
+            .rev()
+            .filter(|&i| bp.recipes[i].robot != objective),
+    );
+    let limits = (0..bp.resources.len())
+        .map(|resource| match resource == objective {
+            true => i32::MAX,
+            false => bp
+                .recipes
+                .iter()
+                .map(|recipe| recipe.costs[resource])
+                .max()
+                .unwrap_or(0),
+        })
+        .collect();
+
+    let mut robots = vec![0; bp.resources.len()];
+    robots[first_robot] = 1;
+    let mut search = Search {
+        blueprint: bp,
+        objective,
+        time_limit: goal.time_limit,
+        order,
+        limits,
+        builds: vec![],
+        best: -1,
+        best_builds: vec![],
+    };
+    search.visit(0, &robots, &vec![0; bp.resources.len()]);
+
+    let mut resources = vec![0; bp.resources.len()];
+    let mut minutes = vec![];
+    for minute in 1..=goal.time_limit {
+        let build = search
+            .best_builds
+            .iter()
+            .find(|(built, _)| *built == minute)
+            .map(|&(_, recipe)| &bp.recipes[recipe]);
+        if let Some(recipe) = build {
+            (0..resources.len()).for_each(|r| resources[r] -= recipe.costs[r]);
+        }
+        (0..resources.len()).for_each(|r| resources[r] += robots[r]);
+        if let Some(recipe) = build {
+            robots[recipe.robot] += 1;
+        }
+        minutes.push(Minute {
+            build: build.map(|recipe| bp.resources[recipe.robot].clone()),
+            robots: robots.clone(),
+            resources: resources.clone(),
+        });
+    }
+    Ok(BuildPlan {
+        resources: bp.resources.clone(),
+        objective,
+        minutes,
+    })
+}
+
+pub fn max_collected(bp: &Blueprint, goal: &Goal) -> Result<i32, BlueprintError> {
+    Ok(best_plan(bp, goal)?.collected())
+}
+
+fn get_quality_level(bp: &Blueprint, goal: &Goal) -> Result<i32, BlueprintError> {
+    Ok(bp.id * max_collected(bp, goal)?)
+}
+
+pub fn part1(blueprints: &[Blueprint], goal: &Goal) -> Result<i32, BlueprintError> {
+    parallel::map(blueprints, |bp| get_quality_level(bp, goal))
+        .into_iter()
+        .sum()
+}
+
+pub fn part2(blueprints: &[Blueprint], goal: &Goal) -> Result<i32, BlueprintError> {
+    let blueprints = &blueprints[..blueprints.len().min(3)];
+    parallel::map(blueprints, |bp| max_collected(bp, goal))
+        .into_iter()
+        .product()
+}
+
+pub struct Config {
+    pub quality_levels: Goal,
+    pub hungry_elephants: Goal,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            quality_levels: Goal::default(),
+            hungry_elephants: Goal {
+                time_limit: HUNGRY_ELEPHANTS_TIME_LIMIT,
+                ..Goal::default()
+            },
+        }
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = Vec<Blueprint>;
+    type Config = Config;
+    type Part1 = i32;
+    type Part2 = i32;
+    type Error = BlueprintError;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        part1(input, &config.quality_levels)
+    }
+
+    fn part2(input: &Self::Input<'_>, config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        part2(input, &config.hungry_elephants)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let blueprints = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(
+            Ok(33),
+            part1(&blueprints, &Config::default().quality_levels)
+        );
+    }
+
+    #[test]
+    fn example_part2() {
+        let blueprints = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(
+            Ok(56 * 62),
+            part2(&blueprints, &Config::default().hungry_elephants)
+        );
+    }
+
+    #[test]
+    fn build_plan() {
+        let blueprints = parse(include_str!("example.txt")).unwrap();
+        let plan = best_plan(&blueprints[0], &Goal::default()).unwrap();
+        assert_eq!(9, plan.collected());
+        assert_eq!(
+            vec![
+                (3, "clay"),
+                (5, "clay"),
+                (7, "clay"),
+                (11, "obsidian"),
+                (12, "clay"),
+                (15, "obsidian"),
+                (18, "geode"),
+                (21, "geode")
+            ],
+            plan.builds()
+        );
+        assert_eq!(
+            "minute  build       ore    clay  obsidian  geode\n\
+             \x20    1            1 (1)   0 (0)     0 (0)  0 (0)\n",
+            plan.to_string()
+                .lines()
+                .take(2)
+                .collect::<Vec<_>>()
+                .join("\n")
+                + "\n"
+        );
+    }
+
+    // every choice of robot to build, or none, in every minute
+    fn exhaustive(
+        bp: &Blueprint,
+        objective: usize,
+        time: i32,
+        robots: &[i32],
+        held: &[i32],
+    ) -> i32 {
+        if time == 0 {
+            return held[objective];
+        }
+        let collect = |held: &[i32]| {
+            (0..held.len())
+                .map(|r| held[r] + robots[r])
+                .collect::<Vec<_>>()
+        };
+        let mut best = exhaustive(bp, objective, time - 1, robots, &collect(held));
+        for recipe in &bp.recipes {
+            let left = (0..held.len())
+                .map(|r| held[r] - recipe.costs[r])
+                .collect::<Vec<_>>();
+            if left.iter().all(|&amount| amount >= 0) {
+                let mut more = robots.to_vec();
+                more[recipe.robot] += 1;
+                best = best.max(exhaustive(bp, objective, time - 1, &more, &collect(&left)));
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn other_resources() {
+        let bp = "Blueprint 7: Each wood robot costs 1 wood. \
+                  Each plank robot costs 2 wood. \
+                  Each chair robot costs 2 wood and 3 plank."
+            .parse::<Blueprint>()
+            .unwrap();
+        assert_eq!(["wood", "plank", "chair"], bp.resources());
+        let goal = Goal {
+            objective: "chair".to_string(),
+            first_robot: "wood".to_string(),
+            time_limit: 10,
+        };
+        let plan = best_plan(&bp, &goal).unwrap();
+        assert_eq!(6, plan.collected());
+        assert_eq!(vec![2, 3, 3], plan.minutes.last().unwrap().robots);
+        assert_eq!(6, exhaustive(&bp, 2, 10, &[1, 0, 0], &[0; 3]));
+
+        let goal = Goal {
+            objective: "stone".to_string(),
+            ..goal
+        };
+        assert_eq!(
+            Err(BlueprintError::UnknownResource {
+                blueprint: 7,
+                resource: "stone".to_string()
+            }),
+            max_collected(&bp, &goal)
+        );
+        assert!(
+            "Blueprint 1: Each ore robot costs 4 ore. Each ore robot costs 2 ore."
+                .parse::<Blueprint>()
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,64 @@
+use std::{env, process};
+
+use aoc_common::{input, take_options, ArgumentError, OrExit};
+use day19::{best_plan, parse, Goal};
+
+// takes --blueprint <ID>, --objective <RESOURCE>, --first-robot <RESOURCE> and
+// --time <MINUTES> out of the arguments, starting from the goal of the first part
+fn goal_from_args(args: Vec<String>) -> Result<(Goal, Option<i32>, Vec<String>), ArgumentError> {
+    let mut goal = Goal::default();
+    let mut blueprint = None;
+    let mut rest = vec![];
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let options = ["--blueprint", "--objective", "--first-robot", "--time"];
+        if !options.contains(&arg.as_str()) {
+            rest.push(arg);
+            continue;
+        }
+        let value = args
+            .next()
+            .ok_or_else(|| ArgumentError::MissingValue(arg.clone()))?;
+        let invalid = || ArgumentError::InvalidValue(arg.clone(), value.clone());
+        match arg.as_str() {
+            "--blueprint" => blueprint = Some(value.parse().map_err(|_| invalid())?),
+            "--objective" => goal.objective = value,
+            "--first-robot" => goal.first_robot = value,
+            _ => goal.time_limit = value.parse().map_err(|_| invalid())?,
+        }
+    }
+    Ok((goal, blueprint, rest))
+}
+
+// `plan` prints the robots each blueprint builds minute by minute for the most of
+// the objective, instead of the answers
+fn main() {
+    let mut args = env::args().skip(1).peekable();
+    if args.peek().map(String::as_str) != Some("plan") {
+        return aoc_common::run::<day19::Day19>(include_str!("input.txt"));
+    }
+    let (goal, blueprint, args) = goal_from_args(take_options(args.skip(1))).or_exit();
+    let input = input::from_args(args.into_iter(), include_str!("input.txt")).or_exit();
+    let blueprints = parse(&input).or_exit();
+    let selected = blueprints
+        .iter()
+        .filter(|bp| blueprint.is_none_or(|id| bp.id() == id))
+        .collect::<Vec<_>>();
+    if let (Some(id), true) = (blueprint, selected.is_empty()) {
+        eprintln!("error: no blueprint {}", id);
+        process::exit(1);
+    }
+    for (i, bp) in selected.into_iter().enumerate() {
+        let plan = best_plan(bp, &goal).or_exit();
+        if i > 0 {
+            println!();
+        }
+        println!(
+            "Blueprint {}: {} {}",
+            bp.id(),
+            plan.collected(),
+            goal.objective
+        );
+        print!("{}", plan);
+    }
+}
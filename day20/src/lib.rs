@@ -0,0 +1,119 @@
+use aoc_common::{
+    parse::{lines, ParseError},
+    NoAnswer, Solution,
+};
+
+const DECRYPTION_KEY: i64 = 811589153;
+
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    let numbers = lines(input)
+        .map(|line| line.parse())
+        .collect::<Result<Vec<i64>, _>>()?;
+    if !numbers.contains(&0) {
+        return Err(ParseError::end_of_input(input, "the file has no zero"));
+    }
+    Ok(numbers)
+}
+
+// None when the decrypted numbers or their sum do not fit
+fn grove_coordinates(numbers: &[i64], key: i64, rounds: usize) -> Option<i64> {
+    let mut numbers = numbers
+        .iter()
+        .map(|n| n.checked_mul(key))
+        .collect::<Option<Vec<_>>>()?
+        .into_iter()
+        .enumerate()
+        .collect::<Vec<(usize, i64)>>();
+
+    for _ in 0..rounds {
+        for n in 0..numbers.len() {
+            let (old_pos, num) = numbers
+                .iter()
+                .enumerate()
+                .find(|(_, (i, _))| *i == n)
+                .map(|(pos, (_, num))| (pos, *num))?;
+
+            // a number on its own has nowhere to move to
+            let others = numbers.len() as i64 - 1;
+            let new_pos = match others {
+                0 => old_pos,
+                _ => (num.rem_euclid(others) + old_pos as i64).rem_euclid(others) as usize,
+            };
+
+            let element = numbers.remove(old_pos);
+            numbers.insert(new_pos, element);
+        }
+    }
+
+    let zero_pos = numbers
+        .iter()
+        .enumerate()
+        .find(|(_, (_, n))| *n == 0)
+        .map(|(i, (_, _))| i)?;
+
+    [1000, 2000, 3000]
+        .into_iter()
+        .map(|offset| numbers[(zero_pos + offset) % numbers.len()].1)
+        .try_fold(0i64, |sum, n| sum.checked_add(n))
+}
+
+pub fn part1(numbers: &[i64]) -> Option<i64> {
+    grove_coordinates(numbers, 1, 1)
+}
+
+pub fn part2(numbers: &[i64]) -> Option<i64> {
+    grove_coordinates(numbers, DECRYPTION_KEY, 10)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input<'a> = Vec<i64>;
+    type Config = ();
+    type Part1 = i64;
+    type Part2 = i64;
+    type Error = NoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        part1(input).ok_or(NoAnswer)
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        part2(input).ok_or(NoAnswer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let numbers = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Some(3), part1(&numbers));
+    }
+
+    #[test]
+    fn example_part2() {
+        let numbers = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Some(1623178306), part2(&numbers));
+    }
+
+    #[test]
+    fn only_zero() {
+        let numbers = parse("0\n").unwrap();
+        assert_eq!(Some(0), part1(&numbers));
+        assert_eq!(Some(0), part2(&numbers));
+    }
+
+    #[test]
+    fn too_large() {
+        let numbers = parse("0\n9223372036854775807\n1\n").unwrap();
+        assert_eq!(None, part1(&numbers));
+        assert_eq!(None, part2(&numbers));
+    }
+}
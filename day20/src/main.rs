@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run::<day20::Day20>(include_str!("input.txt"));
+}
@@ -0,0 +1,817 @@
+use std::{error::Error, fmt::Display};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlgebraError {
+    Overflow,
+    DivisionByZero,
+}
+
+impl Display for AlgebraError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Overflow => write!(f, "the numbers grow too large"),
+            Self::DivisionByZero => write!(f, "division by zero"),
+        }
+    }
+}
+
+impl Error for AlgebraError {}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// an exact fraction in lowest terms with a positive denominator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational::integer(0);
+    pub const ONE: Rational = Rational::integer(1);
+
+    pub const fn integer(value: i128) -> Rational {
+        Rational {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+
+    pub fn new(numerator: i128, denominator: i128) -> Result<Rational, AlgebraError> {
+        if denominator == 0 {
+            return Err(AlgebraError::DivisionByZero);
+        }
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+        Ok(Rational {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        })
+    }
+
+    pub fn numerator(self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.numerator == 0
+    }
+
+    pub fn to_integer(self) -> Option<i128> {
+        (self.denominator == 1).then_some(self.numerator)
+    }
+
+    pub fn checked_add(self, other: Rational) -> Result<Rational, AlgebraError> {
+        let divisor = gcd(self.denominator, other.denominator);
+        let (a, b) = (self.denominator / divisor, other.denominator / divisor);
+        let numerator = self
+            .numerator
+            .checked_mul(b)
+            .zip(other.numerator.checked_mul(a))
+            .and_then(|(x, y)| x.checked_add(y));
+        let denominator = self.denominator.checked_mul(b);
+        Rational::new(
+            numerator.ok_or(AlgebraError::Overflow)?,
+            denominator.ok_or(AlgebraError::Overflow)?,
+        )
+    }
+
+    pub fn negated(self) -> Rational {
+        Rational {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+
+    pub fn checked_sub(self, other: Rational) -> Result<Rational, AlgebraError> {
+        self.checked_add(other.negated())
+    }
+
+    pub fn checked_mul(self, other: Rational) -> Result<Rational, AlgebraError> {
+        // cross cancelling first keeps the products as small as possible
+        let a = gcd(self.numerator, other.denominator).max(1);
+        let b = gcd(other.numerator, self.denominator).max(1);
+        let numerator = (self.numerator / a).checked_mul(other.numerator / b);
+        let denominator = (self.denominator / b).checked_mul(other.denominator / a);
+        Rational::new(
+            numerator.ok_or(AlgebraError::Overflow)?,
+            denominator.ok_or(AlgebraError::Overflow)?,
+        )
+    }
+
+    pub fn recip(self) -> Result<Rational, AlgebraError> {
+        Rational::new(self.denominator, self.numerator)
+    }
+
+    pub fn checked_div(self, other: Rational) -> Result<Rational, AlgebraError> {
+        self.checked_mul(other.recip()?)
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.to_integer() {
+            Some(value) => write!(f, "{}", value),
+            None => write!(f, "{}/{}", self.numerator, self.denominator),
+        }
+    }
+}
+
+// the integer square root, if `value` is a perfect square
+fn exact_sqrt(value: i128) -> Option<i128> {
+    let mut root = (value as f64).sqrt() as i128;
+    while root.checked_mul(root).is_none_or(|square| square > value) {
+        root -= 1;
+    }
+    while (root + 1)
+        .checked_mul(root + 1)
+        .is_some_and(|square| square <= value)
+    {
+        root += 1;
+    }
+    (root * root == value).then_some(root)
+}
+
+// the positive divisors of `value`, or None when it keeps a factor too large to
+// find by trial division
+fn divisors(value: i128) -> Option<Vec<i128>> {
+    const LIMIT: i128 = 1 << 20;
+    let mut rest = value.checked_abs()?;
+    let mut divisors = vec![1];
+    let mut factor = 2;
+    while factor * factor <= rest {
+        if factor > LIMIT {
+            return None;
+        }
+        let count = divisors.len();
+        let mut power = 1;
+        while rest % factor == 0 {
+            rest /= factor;
+            power *= factor;
+            for i in 0..count {
+                divisors.push(divisors[i] * power);
+            }
+        }
+        factor += 1;
+    }
+    if rest > 1 {
+        let count = divisors.len();
+        for i in 0..count {
+            divisors.push(divisors[i] * rest);
+        }
+    }
+    Some(divisors)
+}
+
+// the most fractions tried as the roots of one polynomial
+const MAX_CANDIDATES: usize = 1 << 16;
+
+// the values of an unknown that make a polynomial zero
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Roots {
+    // the polynomial is zero whatever the unknown
+    All,
+    Rational(Vec<Rational>),
+    // roots that are no fractions, such as the square root of two
+    Irrational,
+    // coefficients too large to try every fraction that could be a root
+    Unsolved(usize),
+}
+
+// coefficients of the powers of one unknown, the constant first and without
+// trailing zeros, so zero has none
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    coefficients: Vec<Rational>,
+}
+
+impl Polynomial {
+    pub fn constant(value: Rational) -> Polynomial {
+        Polynomial {
+            coefficients: vec![value],
+        }
+        .trimmed()
+    }
+
+    pub fn unknown() -> Polynomial {
+        Polynomial {
+            coefficients: vec![Rational::ZERO, Rational::ONE],
+        }
+    }
+
+    fn trimmed(mut self) -> Polynomial {
+        while self.coefficients.last().is_some_and(|c| c.is_zero()) {
+            self.coefficients.pop();
+        }
+        self
+    }
+
+    pub fn coefficients(&self) -> &[Rational] {
+        &self.coefficients
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    // zero for constants, including zero itself
+    pub fn degree(&self) -> usize {
+        self.coefficients.len().saturating_sub(1)
+    }
+
+    // the value of a constant polynomial
+    pub fn as_constant(&self) -> Option<Rational> {
+        match self.coefficients[..] {
+            [] => Some(Rational::ZERO),
+            [value] => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn checked_add(&self, other: &Polynomial) -> Result<Polynomial, AlgebraError> {
+        let length = self.coefficients.len().max(other.coefficients.len());
+        let coefficient = |p: &Polynomial, i| *p.coefficients.get(i).unwrap_or(&Rational::ZERO);
+        let coefficients = (0..length)
+            .map(|i| coefficient(self, i).checked_add(coefficient(other, i)))
+            .collect::<Result<_, _>>()?;
+        Ok(Polynomial { coefficients }.trimmed())
+    }
+
+    pub fn negated(&self) -> Polynomial {
+        Polynomial {
+            coefficients: self.coefficients.iter().map(|c| c.negated()).collect(),
+        }
+    }
+
+    pub fn checked_sub(&self, other: &Polynomial) -> Result<Polynomial, AlgebraError> {
+        self.checked_add(&other.negated())
+    }
+
+    pub fn checked_mul(&self, other: &Polynomial) -> Result<Polynomial, AlgebraError> {
+        if self.is_zero() || other.is_zero() {
+            return Ok(Polynomial::constant(Rational::ZERO));
+        }
+        let mut coefficients =
+            vec![Rational::ZERO; self.coefficients.len() + other.coefficients.len() - 1];
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in other.coefficients.iter().enumerate() {
+                coefficients[i + j] = coefficients[i + j].checked_add(a.checked_mul(*b)?)?;
+            }
+        }
+        Ok(Polynomial { coefficients }.trimmed())
+    }
+
+    pub fn scale(&self, factor: Rational) -> Result<Polynomial, AlgebraError> {
+        self.checked_mul(&Polynomial::constant(factor))
+    }
+
+    pub fn evaluate(&self, x: Rational) -> Result<Rational, AlgebraError> {
+        self.coefficients
+            .iter()
+            .rev()
+            .try_fold(Rational::ZERO, |value, c| {
+                value.checked_mul(x)?.checked_add(*c)
+            })
+    }
+
+    // the roots of a polynomial of degree two at most, by the quadratic formula
+    fn quadratic_roots(&self) -> Result<Roots, AlgebraError> {
+        let roots = match self.coefficients[..] {
+            [] => return Ok(Roots::All),
+            [_] => vec![],
+            [c, b] => vec![c.negated().checked_div(b)?],
+            [c, b, a] => {
+                // with the discriminant d/e the roots are (-b ± sqrt(d * e) / e) / 2a
+                let discriminant = b
+                    .checked_mul(b)?
+                    .checked_sub(a.checked_mul(c)?.checked_mul(Rational::integer(4))?)?;
+                let product = discriminant
+                    .numerator()
+                    .checked_mul(discriminant.denominator())
+                    .ok_or(AlgebraError::Overflow)?;
+                if product < 0 {
+                    return Ok(Roots::Rational(vec![]));
+                }
+                let Some(root) = exact_sqrt(product) else {
+                    return Ok(Roots::Irrational);
+                };
+                let root = Rational::new(root, discriminant.denominator())?;
+                let denominator = a.checked_mul(Rational::integer(2))?;
+                vec![
+                    b.negated().checked_sub(root)?.checked_div(denominator)?,
+                    b.negated().checked_add(root)?.checked_div(denominator)?,
+                ]
+            }
+            _ => unreachable!(),
+        };
+        Ok(Roots::Rational(roots))
+    }
+
+    // the same polynomial scaled to integer coefficients without a common factor
+    fn integer_coefficients(&self) -> Result<Vec<i128>, AlgebraError> {
+        let mut multiple = 1;
+        for c in &self.coefficients {
+            multiple = (multiple / gcd(multiple, c.denominator()))
+                .checked_mul(c.denominator())
+                .ok_or(AlgebraError::Overflow)?;
+        }
+        let mut coefficients = self
+            .coefficients
+            .iter()
+            .map(|c| Ok(c.checked_mul(Rational::integer(multiple))?.numerator()))
+            .collect::<Result<Vec<_>, AlgebraError>>()?;
+        let common = coefficients.iter().fold(0, |common, &c| gcd(common, c));
+        for c in &mut coefficients {
+            *c /= common;
+        }
+        Ok(coefficients)
+    }
+
+    // every fraction that can be a root by the rational root theorem, a divisor of
+    // the constant over one of the leading coefficient, or None when there are too
+    // many to try; the constant must not be zero
+    fn candidates(&self) -> Result<Option<Vec<Rational>>, AlgebraError> {
+        let coefficients = self.integer_coefficients()?;
+        let constant = coefficients[0];
+        let leading = coefficients[coefficients.len() - 1];
+        let (Some(numerators), Some(denominators)) = (divisors(constant), divisors(leading)) else {
+            return Ok(None);
+        };
+        if numerators.len() * denominators.len() > MAX_CANDIDATES {
+            return Ok(None);
+        }
+        let mut candidates = vec![];
+        for &q in &denominators {
+            for &p in numerators.iter().filter(|&&p| gcd(p, q) == 1) {
+                candidates.push(Rational::new(p, q)?);
+                candidates.push(Rational::new(-p, q)?);
+            }
+        }
+        Ok(Some(candidates))
+    }
+
+    // the quotient by (x - root), which leaves no remainder for a root
+    fn deflated(&self, root: Rational) -> Result<Polynomial, AlgebraError> {
+        let mut coefficients = vec![Rational::ZERO; self.degree()];
+        let mut carry = Rational::ZERO;
+        for (power, c) in self.coefficients.iter().enumerate().skip(1).rev() {
+            carry = carry.checked_mul(root)?.checked_add(*c)?;
+            coefficients[power - 1] = carry;
+        }
+        Ok(Polynomial { coefficients }.trimmed())
+    }
+
+    fn derivative(&self) -> Result<Polynomial, AlgebraError> {
+        let coefficients = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(power, c)| c.checked_mul(Rational::integer(power as i128)))
+            .collect::<Result<_, _>>()?;
+        Ok(Polynomial { coefficients }.trimmed())
+    }
+
+    fn remainder(&self, divisor: &Polynomial) -> Result<Polynomial, AlgebraError> {
+        let mut rest = self.clone();
+        let leading = divisor.coefficients[divisor.degree()];
+        while !rest.is_zero() && rest.degree() >= divisor.degree() {
+            let shift = rest.degree() - divisor.degree();
+            let factor = rest.coefficients[rest.degree()].checked_div(leading)?;
+            for (i, c) in divisor.coefficients.iter().enumerate() {
+                rest.coefficients[i + shift] =
+                    rest.coefficients[i + shift].checked_sub(c.checked_mul(factor)?)?;
+            }
+            rest = rest.trimmed();
+        }
+        Ok(rest)
+    }
+
+    // the number of distinct real roots by Sturm's theorem, from the signs of the
+    // sequence's leading terms towards either infinity
+    fn real_root_count(&self) -> Result<usize, AlgebraError> {
+        let mut sequence = vec![self.clone(), self.derivative()?];
+        while !sequence[sequence.len() - 1].is_zero() {
+            let (previous, last) = (&sequence[sequence.len() - 2], &sequence[sequence.len() - 1]);
+            let next = previous.remainder(last)?.negated();
+            sequence.push(next);
+        }
+        sequence.pop();
+        let sign_changes = |signs: Vec<i128>| signs.windows(2).filter(|w| w[0] != w[1]).count();
+        let positive = sequence
+            .iter()
+            .map(|p| p.coefficients[p.degree()].numerator().signum())
+            .collect::<Vec<_>>();
+        let negative = sequence
+            .iter()
+            .zip(&positive)
+            .map(|(p, sign)| if p.degree() % 2 == 0 { *sign } else { -sign })
+            .collect();
+        Ok(sign_changes(negative) - sign_changes(positive))
+    }
+
+    // the rational roots are found by trying each candidate and dividing out those
+    // that are roots, down to a quadratic
+    pub fn roots(&self) -> Result<Roots, AlgebraError> {
+        if self.is_zero() {
+            return Ok(Roots::All);
+        }
+        let mut roots = vec![];
+        let mut rest = self.clone();
+        if rest.coefficients[0].is_zero() {
+            roots.push(Rational::ZERO);
+            let zeros = rest.coefficients.iter().take_while(|c| c.is_zero()).count();
+            rest.coefficients.drain(..zeros);
+        }
+        while rest.degree() > 2 {
+            let Some(candidates) = rest.candidates()? else {
+                return Ok(Roots::Unsolved(self.degree()));
+            };
+            let mut found = None;
+            let mut undecided = false;
+            for candidate in candidates {
+                match rest.evaluate(candidate) {
+                    Ok(value) if value.is_zero() => {
+                        found = Some(candidate);
+                        break;
+                    }
+                    Ok(_) => {}
+                    Err(_) => undecided = true,
+                }
+            }
+            match (found, undecided) {
+                (Some(root), _) => {
+                    rest = rest.deflated(root)?;
+                    roots.push(root);
+                }
+                (None, true) => return Ok(Roots::Unsolved(self.degree())),
+                // any other real roots are irrational
+                (None, false) if rest.real_root_count()? > 0 => return Ok(Roots::Irrational),
+                (None, false) => break,
+            }
+        }
+        if rest.degree() <= 2 {
+            match rest.quadratic_roots()? {
+                Roots::Rational(more) => roots.extend(more),
+                other => return Ok(other),
+            }
+        }
+        // smallest first
+        let mut sorted: Vec<Rational> = vec![];
+        for root in roots {
+            let mut at = sorted.len();
+            for (i, other) in sorted.iter().enumerate() {
+                let difference = root.checked_sub(*other)?;
+                if difference.numerator() <= 0 {
+                    at = i;
+                    break;
+                }
+            }
+            if sorted.get(at) != Some(&root) {
+                sorted.insert(at, root);
+            }
+        }
+        Ok(Roots::Rational(sorted))
+    }
+
+    // written out with `variable` for the unknown, the highest power first
+    pub fn format(&self, variable: &str) -> String {
+        let mut text = String::new();
+        for (power, c) in self.coefficients.iter().enumerate().rev() {
+            if c.is_zero() {
+                continue;
+            }
+            let sign = if c.numerator() < 0 { "-" } else { "+" };
+            if text.is_empty() {
+                if sign == "-" {
+                    text.push('-');
+                }
+            } else {
+                text.push_str(&format!(" {} ", sign));
+            }
+            let magnitude = if c.numerator() < 0 { c.negated() } else { *c };
+            let term = match power {
+                0 => String::new(),
+                1 => variable.to_string(),
+                _ => format!("{}^{}", variable, power),
+            };
+            match (magnitude == Rational::ONE, term.is_empty()) {
+                (true, false) => text.push_str(&term),
+                (_, true) => text.push_str(&magnitude.to_string()),
+                (false, false) => text.push_str(&format!("{}*{}", magnitude, term)),
+            }
+        }
+        if text.is_empty() {
+            text.push('0');
+        }
+        text
+    }
+}
+
+// a polynomial divided by another, kept as a plain polynomial whenever the
+// denominator is a constant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expression {
+    numerator: Polynomial,
+    denominator: Polynomial,
+}
+
+impl Expression {
+    pub fn constant(value: Rational) -> Expression {
+        Expression::from(Polynomial::constant(value))
+    }
+
+    pub fn unknown() -> Expression {
+        Expression::from(Polynomial::unknown())
+    }
+
+    fn new(numerator: Polynomial, denominator: Polynomial) -> Result<Expression, AlgebraError> {
+        if numerator.is_zero() && !denominator.is_zero() {
+            return Ok(Expression::from(numerator));
+        }
+        match denominator.as_constant() {
+            Some(value) if value.is_zero() => Err(AlgebraError::DivisionByZero),
+            Some(value) => Ok(Expression::from(numerator.scale(value.recip()?)?)),
+            None => Ok(Expression {
+                numerator,
+                denominator,
+            }),
+        }
+    }
+
+    pub fn numerator(&self) -> &Polynomial {
+        &self.numerator
+    }
+
+    pub fn denominator(&self) -> &Polynomial {
+        &self.denominator
+    }
+
+    pub fn as_constant(&self) -> Option<Rational> {
+        self.denominator
+            .as_constant()
+            .and(self.numerator.as_constant())
+    }
+
+    pub fn checked_add(&self, other: &Expression) -> Result<Expression, AlgebraError> {
+        if self.denominator == other.denominator {
+            return Expression::new(
+                self.numerator.checked_add(&other.numerator)?,
+                self.denominator.clone(),
+            );
+        }
+        Expression::new(
+            self.numerator
+                .checked_mul(&other.denominator)?
+                .checked_add(&other.numerator.checked_mul(&self.denominator)?)?,
+            self.denominator.checked_mul(&other.denominator)?,
+        )
+    }
+
+    pub fn checked_sub(&self, other: &Expression) -> Result<Expression, AlgebraError> {
+        self.checked_add(&Expression {
+            numerator: other.numerator.negated(),
+            denominator: other.denominator.clone(),
+        })
+    }
+
+    pub fn checked_mul(&self, other: &Expression) -> Result<Expression, AlgebraError> {
+        Expression::new(
+            self.numerator.checked_mul(&other.numerator)?,
+            self.denominator.checked_mul(&other.denominator)?,
+        )
+    }
+
+    pub fn checked_div(&self, other: &Expression) -> Result<Expression, AlgebraError> {
+        if other.numerator.is_zero() {
+            return Err(AlgebraError::DivisionByZero);
+        }
+        Expression::new(
+            self.numerator.checked_mul(&other.denominator)?,
+            self.denominator.checked_mul(&other.numerator)?,
+        )
+    }
+
+    // the values of the unknown that make the expression zero, leaving out those
+    // that would divide by zero on the way
+    pub fn roots(&self, divisors: &[Polynomial]) -> Result<Roots, AlgebraError> {
+        let roots = match self.numerator.roots()? {
+            Roots::Rational(roots) => roots,
+            other => return Ok(other),
+        };
+        let mut valid = vec![];
+        for root in roots {
+            let defined = divisors
+                .iter()
+                .chain([&self.denominator])
+                .map(|divisor| divisor.evaluate(root))
+                .collect::<Result<Vec<_>, _>>()?
+                .iter()
+                .all(|value| !value.is_zero());
+            if defined {
+                valid.push(root);
+            }
+        }
+        Ok(Roots::Rational(valid))
+    }
+
+    pub fn format(&self, variable: &str) -> String {
+        match self.denominator.as_constant() {
+            Some(_) => self.numerator.format(variable),
+            None => format!(
+                "({}) / ({})",
+                self.numerator.format(variable),
+                self.denominator.format(variable)
+            ),
+        }
+    }
+}
+
+impl From<Polynomial> for Expression {
+    fn from(numerator: Polynomial) -> Self {
+        Expression {
+            numerator,
+            denominator: Polynomial::constant(Rational::ONE),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fraction(numerator: i128, denominator: i128) -> Rational {
+        Rational::new(numerator, denominator).unwrap()
+    }
+
+    #[test]
+    fn rationals() {
+        assert_eq!(fraction(1, 2), fraction(-3, -6));
+        assert_eq!("-2/3", fraction(4, -6).to_string());
+        assert_eq!(
+            Ok(fraction(5, 6)),
+            fraction(1, 2).checked_add(fraction(1, 3))
+        );
+        assert_eq!(
+            Ok(Rational::ONE),
+            fraction(2, 3).checked_mul(fraction(3, 2))
+        );
+        assert_eq!(
+            Ok(fraction(-3, 2)),
+            Rational::ONE.checked_div(fraction(-2, 3))
+        );
+        assert_eq!(
+            Err(AlgebraError::DivisionByZero),
+            Rational::ONE.checked_div(Rational::ZERO)
+        );
+        assert_eq!(
+            Err(AlgebraError::Overflow),
+            Rational::integer(i128::MAX).checked_add(Rational::ONE)
+        );
+    }
+
+    #[test]
+    fn polynomials() {
+        let x = Polynomial::unknown();
+        let one = Polynomial::constant(Rational::ONE);
+        // (x - 1)(x + 1/2) = x^2 - x/2 - 1/2
+        let p = x
+            .checked_sub(&one)
+            .unwrap()
+            .checked_mul(&x.checked_add(&one.scale(fraction(1, 2)).unwrap()).unwrap())
+            .unwrap();
+        assert_eq!(2, p.degree());
+        assert_eq!("x^2 - 1/2*x - 1/2", p.format("x"));
+        assert_eq!(
+            Ok(Roots::Rational(vec![fraction(-1, 2), Rational::ONE])),
+            p.roots()
+        );
+        assert_eq!(Ok(fraction(9, 2)), p.evaluate(Rational::integer(-2)));
+
+        let square = x.checked_mul(&x).unwrap();
+        assert_eq!(Ok(Roots::Rational(vec![Rational::ZERO])), square.roots());
+        let two = Polynomial::constant(Rational::integer(2));
+        assert_eq!(
+            Ok(Roots::Irrational),
+            square.checked_sub(&two).unwrap().roots()
+        );
+        assert_eq!(
+            Ok(Roots::Rational(vec![])),
+            square.checked_add(&two).unwrap().roots()
+        );
+        assert_eq!(Ok(Roots::All), x.checked_sub(&x).unwrap().roots());
+    }
+
+    fn from_roots(roots: &[Rational]) -> Polynomial {
+        roots
+            .iter()
+            .fold(Polynomial::constant(Rational::ONE), |p, root| {
+                let factor = Polynomial::unknown()
+                    .checked_sub(&Polynomial::constant(*root))
+                    .unwrap();
+                p.checked_mul(&factor).unwrap()
+            })
+    }
+
+    #[test]
+    fn higher_degrees() {
+        let x = Polynomial::unknown();
+        let cube = x.checked_mul(&x).unwrap().checked_mul(&x).unwrap();
+        assert_eq!(Ok(Roots::Rational(vec![Rational::ZERO])), cube.roots());
+
+        let roots = [Rational::integer(-2), Rational::ONE, fraction(3, 2)];
+        let p = from_roots(&[roots[1], roots[2], roots[0]]).scale(fraction(-4, 3));
+        assert_eq!(Ok(Roots::Rational(roots.to_vec())), p.unwrap().roots());
+
+        // (x - 1)^2 (x + 1/3) x^2
+        let p = from_roots(&[
+            Rational::ONE,
+            fraction(-1, 3),
+            Rational::ONE,
+            Rational::ZERO,
+            Rational::ZERO,
+        ]);
+        assert_eq!(
+            Ok(Roots::Rational(vec![
+                fraction(-1, 3),
+                Rational::ZERO,
+                Rational::ONE
+            ])),
+            p.roots()
+        );
+
+        let two = Polynomial::constant(Rational::integer(2));
+        assert_eq!(
+            Ok(Roots::Irrational),
+            cube.checked_sub(&two).unwrap().roots()
+        );
+        // (x - 3)(x^4 + 1) has no more real roots after 3
+        let p = cube
+            .checked_mul(&x)
+            .unwrap()
+            .checked_add(&Polynomial::constant(Rational::ONE))
+            .unwrap()
+            .checked_mul(&from_roots(&[Rational::integer(3)]))
+            .unwrap();
+        assert_eq!(Ok(Roots::Rational(vec![Rational::integer(3)])), p.roots());
+        // (x - 3)(x^4 - 2) has irrational ones
+        let p = cube
+            .checked_mul(&x)
+            .unwrap()
+            .checked_sub(&two)
+            .unwrap()
+            .checked_mul(&from_roots(&[Rational::integer(3)]))
+            .unwrap();
+        assert_eq!(Ok(Roots::Irrational), p.roots());
+
+        // 2^61 - 1 is a prime too large to find by trial division
+        let prime = Polynomial::constant(Rational::integer((1 << 61) - 1));
+        assert_eq!(
+            Ok(Roots::Unsolved(3)),
+            cube.checked_add(&prime).unwrap().roots()
+        );
+    }
+
+    #[test]
+    fn expressions() {
+        let x = Expression::unknown();
+        let two = Expression::constant(Rational::integer(2));
+        // (x + 2) / x - 2 is zero for x = 2
+        let e = x
+            .checked_add(&two)
+            .unwrap()
+            .checked_div(&x)
+            .unwrap()
+            .checked_sub(&two)
+            .unwrap();
+        assert_eq!("(-x + 2) / (x)", e.format("x"));
+        assert_eq!(
+            Ok(Roots::Rational(vec![Rational::integer(2)])),
+            e.roots(&[])
+        );
+        // x / x - 1 is zero everywhere but at x = 0
+        let e = x
+            .checked_div(&x)
+            .unwrap()
+            .checked_sub(&Expression::constant(Rational::ONE))
+            .unwrap();
+        assert_eq!(Some(Rational::ZERO), e.as_constant());
+        assert_eq!(
+            Ok(Expression::constant(fraction(1, 2))),
+            Expression::constant(Rational::ONE).checked_div(&two)
+        );
+        assert_eq!(
+            Err(AlgebraError::DivisionByZero),
+            x.checked_div(&two.checked_sub(&two).unwrap())
+        );
+    }
+}
@@ -0,0 +1,665 @@
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use aoc_common::{
+    parse::{lines, ParseError},
+    Solution,
+};
+
+use algebra::{AlgebraError, Expression, Polynomial, Rational, Roots};
+
+pub mod algebra;
+
+pub enum Op {
+    Num(i64),
+    Add(String, String),
+    Sub(String, String),
+    Mul(String, String),
+    Div(String, String),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum OpErr {
+    MissingOperand,
+    MissingOperator,
+    UnknownOperation,
+}
+
+impl Display for OpErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingOperand => write!(f, "missing operand"),
+            Self::MissingOperator => write!(f, "missing operator"),
+            Self::UnknownOperation => write!(f, "unknown operation"),
+        }
+    }
+}
+
+impl Op {
+    // the monkeys whose numbers an operation waits for
+    pub fn operands(&self) -> Option<(&str, &str)> {
+        match self {
+            Op::Num(_) => None,
+            Op::Add(lhs, rhs) | Op::Sub(lhs, rhs) | Op::Mul(lhs, rhs) | Op::Div(lhs, rhs) => {
+                Some((lhs, rhs))
+            }
+        }
+    }
+
+    fn operator(&self) -> Option<char> {
+        match self {
+            Op::Num(_) => None,
+            Op::Add(..) => Some('+'),
+            Op::Sub(..) => Some('-'),
+            Op::Mul(..) => Some('*'),
+            Op::Div(..) => Some('/'),
+        }
+    }
+}
+
+impl FromStr for Op {
+    type Err = OpErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(number) = s.parse::<i64>() {
+            Ok(Op::Num(number))
+        } else {
+            let mut parts = s.split_whitespace();
+            let lhs = parts.next().ok_or(OpErr::MissingOperand)?.to_string();
+            let op = parts.next().ok_or(OpErr::MissingOperator)?;
+            let rhs = parts.next().ok_or(OpErr::MissingOperand)?.to_string();
+            match op {
+                "+" => Ok(Op::Add(lhs, rhs)),
+                "-" => Ok(Op::Sub(lhs, rhs)),
+                "*" => Ok(Op::Mul(lhs, rhs)),
+                "/" => Ok(Op::Div(lhs, rhs)),
+                _ => Err(OpErr::UnknownOperation),
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MonkeyErr {
+    Parse(ParseError),
+    MissingMonkey(String),
+    NoResult,
+    UnknownRoot,
+    Cycle(Vec<String>),
+    DivisionByZero(String),
+    NotExact(String),
+    Overflow(String),
+    Algebra(AlgebraError),
+    NoSolution,
+    InfinitelyManySolutions,
+    MultipleSolutions(Vec<Rational>),
+    NotInteger(Rational),
+    Irrational,
+    Unsolved(usize),
+}
+
+impl Display for MonkeyErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse(err) => write!(f, "{}", err),
+            Self::MissingMonkey(name) => write!(f, "no monkey called {}", name),
+            Self::NoResult => write!(f, "no result"),
+            Self::UnknownRoot => write!(f, "the root compares numbers and cannot be the unknown"),
+            Self::Cycle(names) => write!(f, "monkeys wait for each other: {}", names.join(" -> ")),
+            Self::DivisionByZero(name) => write!(f, "{} divides by zero", name),
+            Self::NotExact(name) => write!(f, "{} divides with a remainder", name),
+            Self::Overflow(name) => write!(f, "{}'s number does not fit 64 bits", name),
+            Self::Algebra(err) => write!(f, "{}", err),
+            Self::NoSolution => write!(f, "no number makes both sides equal"),
+            Self::InfinitelyManySolutions => write!(f, "any number makes both sides equal"),
+            Self::MultipleSolutions(solutions) => {
+                let solutions = solutions
+                    .iter()
+                    .map(|solution| solution.to_string())
+                    .collect::<Vec<_>>();
+                write!(
+                    f,
+                    "several numbers make both sides equal: {}",
+                    solutions.join(", ")
+                )
+            }
+            Self::NotInteger(solution) => write!(f, "the solution {} is no integer", solution),
+            Self::Irrational => write!(f, "the solutions are irrational"),
+            Self::Unsolved(degree) => write!(
+                f,
+                "the numbers in the equation of degree {} are too large to solve it",
+                degree
+            ),
+        }
+    }
+}
+
+impl From<ParseError> for MonkeyErr {
+    fn from(err: ParseError) -> Self {
+        MonkeyErr::Parse(err)
+    }
+}
+
+impl From<AlgebraError> for MonkeyErr {
+    fn from(err: AlgebraError) -> Self {
+        MonkeyErr::Algebra(err)
+    }
+}
+
+fn parse_monkey(line: &str) -> Result<(&str, Op), String> {
+    let (name, op) = line.split_once(": ").ok_or("expected \"<name>: <job>\"")?;
+    Ok((name, op.parse::<Op>().map_err(|err| err.to_string())?))
+}
+
+pub fn parse(input: &str) -> Result<HashMap<&str, Op>, ParseError> {
+    let mut monkeys = HashMap::new();
+    for line in lines(input) {
+        let (name, op) = parse_monkey(line.text).map_err(|err| line.error(err))?;
+        // a second job would quietly make it a different riddle
+        if monkeys.insert(name, op).is_some() {
+            return Err(line.error(format!("{} already has a job", name)));
+        }
+    }
+    Ok(monkeys)
+}
+
+const ROOT: &str = "root";
+const HUMAN: &str = "humn";
+
+// the monkeys `target` waits for, directly or not, and `target` itself, each after
+// those it waits for; the `unknown` monkey's own job does not count
+pub fn topological_order<'a>(
+    monkeys: &HashMap<&'a str, Op>,
+    target: &str,
+    unknown: Option<&str>,
+) -> Result<Vec<&'a str>, MonkeyErr> {
+    let key = |name: &str| {
+        monkeys
+            .get_key_value(name)
+            .map(|(key, _)| *key)
+            .ok_or_else(|| MonkeyErr::MissingMonkey(name.to_string()))
+    };
+    if let Some(unknown) = unknown {
+        key(unknown)?;
+    }
+    // false for the monkeys on the current path, a monkey waiting for one of those
+    // closes a cycle
+    let mut done = HashMap::new();
+    let mut order = vec![];
+    let mut stack = vec![(key(target)?, false)];
+
+    while let Some((name, expanded)) = stack.pop() {
+        if expanded {
+            done.insert(name, true);
+            order.push(name);
+            continue;
+        }
+        if done.contains_key(name) {
+            continue;
+        }
+        done.insert(name, false);
+        stack.push((name, true));
+        if unknown == Some(name) {
+            continue;
+        }
+        let Some((lhs, rhs)) = monkeys[name].operands() else {
+            continue;
+        };
+        for operand in [rhs, lhs] {
+            let operand = key(operand)?;
+            match done.get(operand) {
+                Some(true) => {}
+                Some(false) => {
+                    let path = stack
+                        .iter()
+                        .filter(|(_, expanded)| *expanded)
+                        .map(|(name, _)| name.to_string())
+                        .collect::<Vec<_>>();
+                    let start = path.iter().position(|name| name == operand).unwrap();
+                    let mut cycle = path[start..].to_vec();
+                    cycle.push(operand.to_string());
+                    return Err(MonkeyErr::Cycle(cycle));
+                }
+                None => stack.push((operand, false)),
+            }
+        }
+    }
+
+    Ok(order)
+}
+
+fn checked(name: &str, result: Option<i64>) -> Result<i64, MonkeyErr> {
+    result.ok_or_else(|| MonkeyErr::Overflow(name.to_string()))
+}
+
+// the number `target` yells, working out every monkey's number once
+pub fn evaluate(monkeys: &HashMap<&str, Op>, target: &str) -> Result<i64, MonkeyErr> {
+    let mut values: HashMap<&str, i64> = HashMap::new();
+    for name in topological_order(monkeys, target, None)? {
+        let value = match &monkeys[name] {
+            Op::Num(number) => *number,
+            Op::Add(lhs, rhs) => {
+                checked(name, values[lhs.as_str()].checked_add(values[rhs.as_str()]))?
+            }
+            Op::Sub(lhs, rhs) => {
+                checked(name, values[lhs.as_str()].checked_sub(values[rhs.as_str()]))?
+            }
+            Op::Mul(lhs, rhs) => {
+                checked(name, values[lhs.as_str()].checked_mul(values[rhs.as_str()]))?
+            }
+            Op::Div(lhs, rhs) => {
+                let (lhs, rhs) = (values[lhs.as_str()], values[rhs.as_str()]);
+                if rhs == 0 {
+                    return Err(MonkeyErr::DivisionByZero(name.to_string()));
+                }
+                if checked(name, lhs.checked_rem(rhs))? != 0 {
+                    return Err(MonkeyErr::NotExact(name.to_string()));
+                }
+                checked(name, lhs.checked_div(rhs))?
+            }
+        };
+        values.insert(name, value);
+    }
+    Ok(values[target])
+}
+
+// what the monkeys in `order` yell in terms of the `unknown` monkey, collecting the
+// divisors that must not become zero
+fn expressions<'a>(
+    monkeys: &HashMap<&'a str, Op>,
+    order: &[&'a str],
+    unknown: &str,
+    divisors: &mut Vec<Polynomial>,
+) -> Result<HashMap<&'a str, Expression>, MonkeyErr> {
+    let mut expressions: HashMap<&str, Expression> = HashMap::new();
+    for &name in order {
+        let op = &monkeys[name];
+        let expression = match op.operands() {
+            _ if name == unknown => Expression::unknown(),
+            None => match op {
+                Op::Num(number) => Expression::constant(Rational::integer(*number as i128)),
+                _ => unreachable!(),
+            },
+            Some((lhs, rhs)) => {
+                let (lhs, rhs) = (&expressions[lhs], &expressions[rhs]);
+                match op {
+                    Op::Add(..) => lhs.checked_add(rhs)?,
+                    Op::Sub(..) => lhs.checked_sub(rhs)?,
+                    Op::Mul(..) => lhs.checked_mul(rhs)?,
+                    Op::Div(..) => {
+                        divisors.push(rhs.numerator().clone());
+                        lhs.checked_div(rhs)?
+                    }
+                    Op::Num(_) => unreachable!(),
+                }
+            }
+        };
+        expressions.insert(name, expression);
+    }
+    Ok(expressions)
+}
+
+// what `target` yells as a polynomial, or a fraction of them, in the number the
+// `unknown` monkey yells
+pub fn simplify(
+    target: &str,
+    monkeys: &HashMap<&str, Op>,
+    unknown: &str,
+) -> Result<Expression, MonkeyErr> {
+    let order = topological_order(monkeys, target, Some(unknown))?;
+    let mut expressions = expressions(monkeys, &order, unknown, &mut vec![])?;
+    Ok(expressions.remove(target).unwrap())
+}
+
+// the number `unknown` has to yell for both of the numbers `root` waits for to be the same
+pub fn solve(monkeys: &HashMap<&str, Op>, root: &str, unknown: &str) -> Result<i64, MonkeyErr> {
+    let (lhs, rhs) = monkeys
+        .get(root)
+        .ok_or_else(|| MonkeyErr::MissingMonkey(root.to_string()))?
+        .operands()
+        .ok_or(MonkeyErr::NoResult)?;
+    if root == unknown {
+        return Err(MonkeyErr::UnknownRoot);
+    }
+    let order = topological_order(monkeys, root, Some(unknown))?;
+    let mut divisors = vec![];
+    // everything but the root, which compares rather than calculates
+    let expressions = expressions(monkeys, &order[..order.len() - 1], unknown, &mut divisors)?;
+    match expressions[lhs]
+        .checked_sub(&expressions[rhs])?
+        .roots(&divisors)?
+    {
+        Roots::All => Err(MonkeyErr::InfinitelyManySolutions),
+        Roots::Rational(solutions) => match solutions[..] {
+            [] => Err(MonkeyErr::NoSolution),
+            [solution] => {
+                let value = solution
+                    .to_integer()
+                    .ok_or(MonkeyErr::NotInteger(solution))?;
+                i64::try_from(value).map_err(|_| MonkeyErr::Algebra(AlgebraError::Overflow))
+            }
+            _ => Err(MonkeyErr::MultipleSolutions(solutions)),
+        },
+        Roots::Irrational => Err(MonkeyErr::Irrational),
+        Roots::Unsolved(degree) => Err(MonkeyErr::Unsolved(degree)),
+    }
+}
+
+// `target` as one fully parenthesised expression, with the `unknown` monkey by name
+pub fn infix(
+    monkeys: &HashMap<&str, Op>,
+    target: &str,
+    unknown: Option<&str>,
+) -> Result<String, MonkeyErr> {
+    let mut terms: HashMap<&str, String> = HashMap::new();
+    for name in topological_order(monkeys, target, unknown)? {
+        let op = &monkeys[name];
+        let term = match (op, op.operands()) {
+            _ if Some(name) == unknown => name.to_string(),
+            (Op::Num(number), _) if *number < 0 => format!("({})", number),
+            (Op::Num(number), _) => number.to_string(),
+            (_, Some((lhs, rhs))) => {
+                let term = format!("{} {} {}", terms[lhs], op.operator().unwrap(), terms[rhs]);
+                // no parentheses around the whole
+                if name == target {
+                    term
+                } else {
+                    format!("({})", term)
+                }
+            }
+            (_, None) => unreachable!(),
+        };
+        terms.insert(name, term);
+    }
+    Ok(terms.remove(target).unwrap())
+}
+
+// the monkeys `root` waits for as a Graphviz graph, an arrow from each monkey to those
+// it waits for, with the ones between `root` and `unknown` in red
+pub fn dot(monkeys: &HashMap<&str, Op>, root: &str, unknown: &str) -> Result<String, MonkeyErr> {
+    const HIGHLIGHT: &str = ", color=red, fontcolor=red, penwidth=2";
+    if !monkeys.contains_key(unknown) {
+        return Err(MonkeyErr::MissingMonkey(unknown.to_string()));
+    }
+    let order = topological_order(monkeys, root, None)?;
+    let mut on_path: HashMap<&str, bool> = HashMap::new();
+    for &name in &order {
+        let waits = monkeys[name]
+            .operands()
+            .is_some_and(|(lhs, rhs)| on_path[lhs] || on_path[rhs]);
+        on_path.insert(name, name == unknown || waits);
+    }
+
+    let mut dot = String::from("digraph monkeys {\n    ordering=out;\n    node [shape=box];\n");
+    for &name in order.iter().rev() {
+        let op = &monkeys[name];
+        let job = match op {
+            Op::Num(number) => number.to_string(),
+            _ => op.operator().unwrap().to_string(),
+        };
+        let style = if on_path[name] { HIGHLIGHT } else { "" };
+        dot.push_str(&format!(
+            "    \"{}\" [label=\"{}\\n{}\"{}];\n",
+            name, name, job, style
+        ));
+        if let Some((lhs, rhs)) = op.operands() {
+            for operand in [lhs, rhs] {
+                let style = if on_path[name] && on_path[operand] {
+                    HIGHLIGHT
+                } else {
+                    ""
+                };
+                let style = style.trim_start_matches(", ");
+                let attributes = if style.is_empty() {
+                    String::new()
+                } else {
+                    format!(" [{}]", style)
+                };
+                dot.push_str(&format!(
+                    "    \"{}\" -> \"{}\"{};\n",
+                    name, operand, attributes
+                ));
+            }
+        }
+    }
+    dot.push_str("}\n");
+    Ok(dot)
+}
+
+pub fn part1(monkeys: &HashMap<&str, Op>, root: &str) -> Result<i64, MonkeyErr> {
+    evaluate(monkeys, root)
+}
+
+pub fn part2(monkeys: &HashMap<&str, Op>, root: &str, unknown: &str) -> Result<i64, MonkeyErr> {
+    solve(monkeys, root, unknown)
+}
+
+// the monkey whose number is wanted and the one whose number is made up
+pub struct Config {
+    pub root: String,
+    pub unknown: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            root: ROOT.to_string(),
+            unknown: HUMAN.to_string(),
+        }
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input<'a> = HashMap<&'a str, Op>;
+    type Config = Config;
+    type Part1 = i64;
+    type Part2 = i64;
+    type Error = MonkeyErr;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        part1(input, &config.root)
+    }
+
+    fn part2(input: &Self::Input<'_>, config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        part2(input, &config.root, &config.unknown)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let monkeys = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Ok(152), part1(&monkeys, ROOT));
+    }
+
+    #[test]
+    fn example_part2() {
+        let monkeys = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Ok(301), part2(&monkeys, ROOT, HUMAN));
+    }
+
+    #[test]
+    fn other_unknowns() {
+        let monkeys = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Ok(2), solve(&monkeys, ROOT, "sjmn"));
+        assert_eq!(Ok(4), solve(&monkeys, "cczh", "lgvd"));
+        assert_eq!(
+            Err(MonkeyErr::NotInteger(Rational::new(12, 5).unwrap())),
+            solve(&monkeys, ROOT, "hmdt")
+        );
+        assert_eq!(
+            "1/2*humn - 1/2",
+            simplify("pppw", &monkeys, HUMAN).unwrap().format(HUMAN)
+        );
+        assert_eq!(Err(MonkeyErr::NoResult), solve(&monkeys, "dbpl", HUMAN));
+        assert_eq!(
+            Err(MonkeyErr::MissingMonkey("nope".to_string())),
+            solve(&monkeys, ROOT, "nope")
+        );
+        assert_eq!(
+            Err(MonkeyErr::MissingMonkey("nope".to_string())),
+            simplify("pppw", &monkeys, "nope")
+        );
+    }
+
+    #[test]
+    fn unusual_solutions() {
+        let solve_with = |replacements: &[(&str, &str)]| {
+            let mut input = include_str!("example.txt").to_string();
+            for (from, to) in replacements {
+                input = input.replacen(from, to, 1);
+            }
+            solve(&parse(&input).unwrap(), ROOT, HUMAN)
+        };
+        assert_eq!(
+            Err(MonkeyErr::NotInteger(Rational::new(601, 2).unwrap())),
+            solve_with(&[("sllz: 4", "sllz: 5")])
+        );
+        let square = ("ptdq: humn - dvpt", "ptdq: humn * humn");
+        assert_eq!(
+            Err(MonkeyErr::MultipleSolutions(vec![
+                Rational::integer(-5),
+                Rational::integer(5)
+            ])),
+            solve_with(&[square, ("sllz: 4", "sllz: 550")])
+        );
+        assert_eq!(Err(MonkeyErr::Irrational), solve_with(&[square]));
+        // humn^3 - 3 humn^2 - 700 = 0
+        assert_eq!(
+            Ok(10),
+            solve_with(&[("ljgn: 2", "ljgn: humn * humn"), ("sllz: 4", "sllz: -100")])
+        );
+        assert_eq!(
+            Err(MonkeyErr::InfinitelyManySolutions),
+            solve_with(&[("root: pppw + sjmn", "root: pppw + pppw")])
+        );
+        assert_eq!(
+            Err(MonkeyErr::NoSolution),
+            solve_with(&[("ptdq: humn - dvpt", "ptdq: dvpt - dvpt")])
+        );
+        // x / (x - 3) = 1 would need x - 3 = x
+        assert_eq!(
+            Err(MonkeyErr::NoSolution),
+            solve_with(&[
+                ("root: pppw + sjmn", "root: pppw + lfqf"),
+                ("pppw: cczh / lfqf", "pppw: humn / ptdq"),
+                ("lfqf: 4", "lfqf: 1"),
+            ])
+        );
+        assert_eq!(
+            Err(MonkeyErr::MissingMonkey("dvpt".to_string())),
+            solve_with(&[("dvpt: 3\n", "")])
+        );
+    }
+
+    #[test]
+    fn evaluation_order() {
+        let monkeys = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(
+            Ok(vec!["hmdt", "zczc", "drzm", "dbpl", "sjmn"]),
+            topological_order(&monkeys, "sjmn", None)
+        );
+        let order = topological_order(&monkeys, ROOT, Some(HUMAN)).unwrap();
+        assert_eq!(monkeys.len(), order.len());
+        assert_eq!(Some(&ROOT), order.last());
+        for (i, name) in order.iter().enumerate() {
+            if let Some((lhs, rhs)) = monkeys[name].operands() {
+                assert!(order[..i].contains(&lhs) && order[..i].contains(&rhs));
+            }
+        }
+    }
+
+    #[test]
+    fn evaluation_errors() {
+        let evaluate_with = |from: &str, to: &str| {
+            let input = include_str!("example.txt").replacen(from, to, 1);
+            evaluate(&parse(&input).unwrap(), ROOT)
+        };
+        assert_eq!(
+            Err(MonkeyErr::Cycle(vec![
+                "sjmn".to_string(),
+                "drzm".to_string(),
+                "hmdt".to_string(),
+                "sjmn".to_string()
+            ])),
+            evaluate_with("hmdt: 32", "hmdt: sjmn + zczc")
+        );
+        assert_eq!(
+            Err(MonkeyErr::DivisionByZero("pppw".to_string())),
+            evaluate_with("lfqf: 4", "lfqf: 0")
+        );
+        assert_eq!(
+            Err(MonkeyErr::NotExact("pppw".to_string())),
+            evaluate_with("lfqf: 4", "lfqf: 3")
+        );
+        assert_eq!(
+            Err(MonkeyErr::Overflow("sjmn".to_string())),
+            evaluate_with("dbpl: 5", "dbpl: 999999999999999999")
+        );
+        let input = "root: mini / minu\nmini: -9223372036854775808\nminu: -1\n";
+        assert_eq!(
+            Err(MonkeyErr::Overflow("root".to_string())),
+            evaluate(&parse(input).unwrap(), ROOT)
+        );
+        assert_eq!(
+            Err(MonkeyErr::UnknownRoot),
+            solve(&parse(include_str!("example.txt")).unwrap(), ROOT, ROOT)
+        );
+        assert_eq!(
+            Err(MonkeyErr::Cycle(vec![
+                "lgvd".to_string(),
+                "lgvd".to_string()
+            ])),
+            solve(
+                &parse(&include_str!("example.txt").replacen("ljgn * ptdq", "ljgn * lgvd", 1))
+                    .unwrap(),
+                ROOT,
+                HUMAN
+            )
+        );
+    }
+
+    #[test]
+    fn duplicate_monkeys() {
+        let input = format!("{}dbpl: 6\n", include_str!("example.txt"));
+        let err = parse(&input).err().unwrap();
+        assert_eq!(16, err.line());
+        assert_eq!("dbpl already has a job", err.reason());
+    }
+
+    #[test]
+    fn exports() {
+        let monkeys = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(
+            Ok("((4 + (2 * (humn - 3))) / 4) + ((32 - 2) * 5)".to_string()),
+            infix(&monkeys, ROOT, Some(HUMAN))
+        );
+        assert_eq!(
+            Ok("(32 - 2) * 5".to_string()),
+            infix(&monkeys, "sjmn", None)
+        );
+        assert_eq!(Ok("5".to_string()), infix(&monkeys, HUMAN, None));
+
+        let dot = dot(&monkeys, ROOT, HUMAN).unwrap();
+        assert!(dot.starts_with("digraph monkeys {\n"));
+        assert!(dot.contains(
+            "    \"root\" [label=\"root\\n+\", color=red, fontcolor=red, penwidth=2];\n"
+        ));
+        assert!(dot.contains("    \"root\" -> \"pppw\" [color=red, fontcolor=red, penwidth=2];\n"));
+        assert!(dot.contains("    \"root\" -> \"sjmn\";\n"));
+        assert!(dot.contains("    \"dbpl\" [label=\"dbpl\\n5\"];\n"));
+        assert_eq!(14, dot.lines().filter(|line| line.contains("->")).count());
+        assert_eq!(
+            Err(MonkeyErr::MissingMonkey("nope".to_string())),
+            super::dot(&monkeys, ROOT, "nope")
+        );
+    }
+}
@@ -0,0 +1,22 @@
+use std::env;
+
+use aoc_common::{input, take_options, OrExit};
+use day21::{dot, infix, parse, Config, Day21};
+
+// `dot` prints the monkeys as a Graphviz graph with the path from the root to the
+// human highlighted and `infix` as one expression, instead of the answers
+fn main() {
+    let mut args = env::args().skip(1).peekable();
+    let command = match args.peek().map(String::as_str) {
+        Some("dot" | "infix") => args.next().unwrap(),
+        _ => return aoc_common::run::<Day21>(include_str!("input.txt")),
+    };
+    let args = take_options(args);
+    let input = input::from_args(args.into_iter(), include_str!("input.txt")).or_exit();
+    let monkeys = parse(&input).or_exit();
+    let Config { root, unknown } = Config::default();
+    match command.as_str() {
+        "dot" => print!("{}", dot(&monkeys, &root, &unknown).or_exit()),
+        _ => println!("{}", infix(&monkeys, &root, Some(&unknown)).or_exit()),
+    }
+}
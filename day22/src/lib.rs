@@ -0,0 +1,672 @@
+use std::{collections::HashMap, error::Error, fmt::Display};
+
+use aoc_common::{
+    parse::{blocks, Block, Line, ParseError},
+    visualize, Direction, Point2, Solution,
+};
+
+// the facing values used by the final password
+fn facing(direction: Direction) -> usize {
+    match direction {
+        Direction::Right => 0,
+        Direction::Down => 1,
+        Direction::Left => 2,
+        Direction::Up => 3,
+    }
+}
+
+enum PathStep {
+    Forward(usize),
+    TurnLeft,
+    TurnRight,
+}
+
+fn parse_path(path: Line) -> Result<Vec<PathStep>, ParseError> {
+    let mut result = vec![];
+    let mut digits = String::new();
+    let forward = |digits: &mut String| -> Result<PathStep, ParseError> {
+        let number = digits
+            .parse()
+            .map_err(|_| path.error("expected a number of tiles before each turn"))?;
+        digits.clear();
+        Ok(PathStep::Forward(number))
+    };
+    for ch in path.text.trim().chars() {
+        match ch {
+            'L' => {
+                result.push(forward(&mut digits)?);
+                result.push(PathStep::TurnLeft);
+            }
+            'R' => {
+                result.push(forward(&mut digits)?);
+                result.push(PathStep::TurnRight);
+            }
+            '0'..='9' => digits.push(ch),
+            _ => return Err(path.error(format!("unexpected {:?}", ch))),
+        }
+    }
+    if !digits.is_empty() {
+        result.push(forward(&mut digits)?);
+    }
+    Ok(result)
+}
+
+#[derive(PartialEq)]
+enum MapSquare {
+    Wall,
+    Open,
+    Void,
+}
+
+fn parse_map(map: Block) -> Result<Vec<Vec<MapSquare>>, ParseError> {
+    let rows = map
+        .lines()
+        .map(|line| {
+            line.text
+                .chars()
+                .map(|ch| match ch {
+                    '#' => Ok(MapSquare::Wall),
+                    '.' => Ok(MapSquare::Open),
+                    ' ' => Ok(MapSquare::Void),
+                    _ => Err(line.error(format!("unexpected {:?}", ch))),
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    match rows.first() {
+        Some(row) if row.contains(&MapSquare::Open) => Ok(rows),
+        _ => Err(map.error("no open tile to start from on the top row")),
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum CubeEdge {
+    Straight(usize, usize),
+    TurnLeft(usize, usize),
+    TurnRight(usize, usize),
+    Reverse(usize, usize),
+}
+
+#[derive(Clone)]
+pub struct Cube {
+    size: usize,
+    net: Vec<Vec<Option<[CubeEdge; 4]>>>,
+}
+
+// the cube a part 2 answer was asked for has a net the map does not follow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CubeMismatch;
+
+impl Display for CubeMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the map does not fold into the cube")
+    }
+}
+
+impl Error for CubeMismatch {}
+
+impl Cube {
+    fn face(&self, row: usize, col: usize) -> Option<&[CubeEdge; 4]> {
+        self.net.get(row)?.get(col)?.as_ref()
+    }
+
+    // every tile of the map on a face of the net, every face covered by tiles, and
+    // every edge leading to another face, so walking never leaves the map
+    fn fits(&self, map: &[Vec<MapSquare>]) -> bool {
+        if self.size == 0 {
+            return false;
+        }
+        let tiles_on_faces = map.iter().enumerate().all(|(row, squares)| {
+            squares.iter().enumerate().all(|(column, square)| {
+                *square == MapSquare::Void
+                    || self.face(row / self.size, column / self.size).is_some()
+            })
+        });
+        let faces_covered = self.net.iter().enumerate().all(|(r, faces)| {
+            faces.iter().enumerate().all(|(c, face)| {
+                let Some(edges) = face else {
+                    return true;
+                };
+                let covered = (r * self.size..(r + 1) * self.size).all(|row| {
+                    (c * self.size..(c + 1) * self.size).all(|column| {
+                        map.get(row)
+                            .and_then(|squares| squares.get(column))
+                            .is_some_and(|square| *square != MapSquare::Void)
+                    })
+                });
+                covered
+                    && edges.iter().all(|edge| match *edge {
+                        CubeEdge::Straight(r, c)
+                        | CubeEdge::TurnLeft(r, c)
+                        | CubeEdge::TurnRight(r, c)
+                        | CubeEdge::Reverse(r, c) => self.face(r, c).is_some(),
+                    })
+            })
+        });
+        tiles_on_faces && faces_covered
+    }
+
+    fn net_pos(&self, pos: Location) -> (usize, usize) {
+        (pos.row / self.size, pos.column / self.size)
+    }
+
+    fn right(&self, pos: Location) -> Location {
+        let (row, col) = self.net_pos(pos);
+        if pos.column % self.size < self.size - 1 {
+            return Location {
+                row: pos.row,
+                column: pos.column + 1,
+                direction: pos.direction,
+            };
+        }
+        let edge = self.net[row][col].unwrap()[0];
+        match edge {
+            CubeEdge::Straight(r, c) => Location {
+                row: r * self.size + pos.row % self.size,
+                column: c * self.size,
+                direction: Direction::Right,
+            },
+            CubeEdge::TurnLeft(r, c) => Location {
+                row: (r + 1) * self.size - 1,
+                column: pos.row % self.size + c * self.size,
+                direction: Direction::Up,
+            },
+            CubeEdge::TurnRight(r, c) => Location {
+                row: r * self.size,
+                column: (c + 1) * self.size - 1 - pos.row % self.size,
+                direction: Direction::Down,
+            },
+            CubeEdge::Reverse(r, c) => Location {
+                row: (r + 1) * self.size - 1 - pos.row % self.size,
+                column: (c + 1) * self.size - 1,
+                direction: Direction::Left,
+            },
+        }
+    }
+
+    fn down(&self, pos: Location) -> Location {
+        let (row, col) = self.net_pos(pos);
+        if pos.row % self.size < self.size - 1 {
+            return Location {
+                row: pos.row + 1,
+                column: pos.column,
+                direction: pos.direction,
+            };
+        }
+        let edge = self.net[row][col].unwrap()[1];
+        match edge {
+            CubeEdge::Straight(r, c) => Location {
+                row: r * self.size,
+                column: c * self.size + pos.column % self.size,
+                direction: Direction::Down,
+            },
+            CubeEdge::TurnLeft(r, c) => Location {
+                row: (r + 1) * self.size - 1 - pos.column % self.size,
+                column: c * self.size,
+                direction: Direction::Right,
+            },
+            CubeEdge::TurnRight(r, c) => Location {
+                row: r * self.size + pos.column % self.size,
+                column: (c + 1) * self.size - 1,
+                direction: Direction::Left,
+            },
+            CubeEdge::Reverse(r, c) => Location {
+                row: (r + 1) * self.size - 1,
+                column: (c + 1) * self.size - 1 - pos.column % self.size,
+                direction: Direction::Up,
+            },
+        }
+    }
+
+    fn left(&self, pos: Location) -> Location {
+        let (row, col) = self.net_pos(pos);
+        if !pos.column.is_multiple_of(self.size) {
+            return Location {
+                row: pos.row,
+                column: pos.column - 1,
+                direction: pos.direction,
+            };
+        }
+        let edge = self.net[row][col].unwrap()[2];
+        match edge {
+            CubeEdge::Straight(r, c) => Location {
+                row: r * self.size + pos.row % self.size,
+                column: (c + 1) * self.size - 1,
+                direction: Direction::Left,
+            },
+            CubeEdge::TurnLeft(r, c) => Location {
+                row: r * self.size,
+                column: pos.row % self.size + c * self.size,
+                direction: Direction::Down,
+            },
+            CubeEdge::TurnRight(r, c) => Location {
+                row: (r + 1) * self.size - 1,
+                column: (c + 1) * self.size - 1 - pos.row % self.size,
+                direction: Direction::Up,
+            },
+            CubeEdge::Reverse(r, c) => Location {
+                row: (r + 1) * self.size - 1 - pos.row % self.size,
+                column: c * self.size,
+                direction: Direction::Right,
+            },
+        }
+    }
+
+    fn up(&self, pos: Location) -> Location {
+        let (row, col) = self.net_pos(pos);
+        if !pos.row.is_multiple_of(self.size) {
+            return Location {
+                row: pos.row - 1,
+                column: pos.column,
+                direction: pos.direction,
+            };
+        }
+        let edge = self.net[row][col].unwrap()[3];
+        match edge {
+            CubeEdge::Straight(r, c) => Location {
+                row: (r + 1) * self.size - 1,
+                column: c * self.size + pos.column % self.size,
+                direction: Direction::Up,
+            },
+            CubeEdge::TurnLeft(r, c) => Location {
+                row: (r + 1) * self.size - 1 - pos.column % self.size,
+                column: (c + 1) * self.size - 1,
+                direction: Direction::Left,
+            },
+            CubeEdge::TurnRight(r, c) => Location {
+                row: r * self.size + pos.column % self.size,
+                column: c * self.size,
+                direction: Direction::Right,
+            },
+            CubeEdge::Reverse(r, c) => Location {
+                row: r * self.size,
+                column: (c + 1) * self.size - 1 - pos.column % self.size,
+                direction: Direction::Down,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Location {
+    row: usize,
+    column: usize,
+    direction: Direction,
+}
+
+fn find_start(map: &[Vec<MapSquare>]) -> Location {
+    Location {
+        row: 0,
+        column: map[0]
+            .iter()
+            .enumerate()
+            .find(|(_, x)| **x == MapSquare::Open)
+            .unwrap()
+            .0,
+        direction: Direction::Right,
+    }
+}
+
+fn find_right(pos: &Location, map: &[Vec<MapSquare>]) -> Location {
+    let row = &map[pos.row];
+    let new_column = match row.get(pos.column + 1) {
+        Some(MapSquare::Void) | None => {
+            row.iter()
+                .enumerate()
+                .find(|(_, x)| **x != MapSquare::Void)
+                .unwrap()
+                .0
+        }
+        Some(_) => pos.column + 1,
+    };
+    Location {
+        row: pos.row,
+        column: new_column,
+        direction: pos.direction,
+    }
+}
+
+fn find_down(pos: &Location, map: &[Vec<MapSquare>]) -> Location {
+    if let Some(row) = map.get(pos.row + 1) {
+        if let Some(square) = row.get(pos.column) {
+            if *square != MapSquare::Void {
+                return Location {
+                    row: pos.row + 1,
+                    column: pos.column,
+                    direction: pos.direction,
+                };
+            }
+        }
+    }
+    let new_row = map
+        .iter()
+        .enumerate()
+        .find(|(_, row)| !matches!(row.get(pos.column), Some(MapSquare::Void) | None))
+        .unwrap()
+        .0;
+    Location {
+        row: new_row,
+        column: pos.column,
+        direction: pos.direction,
+    }
+}
+
+fn find_left(pos: &Location, map: &[Vec<MapSquare>]) -> Location {
+    let row = &map[pos.row];
+    let new_column = if pos.column > 0 {
+        match row.get(pos.column - 1) {
+            Some(MapSquare::Void) => row.len() - 1,
+            _ => pos.column - 1,
+        }
+    } else {
+        row.len() - 1
+    };
+    Location {
+        row: pos.row,
+        column: new_column,
+        direction: pos.direction,
+    }
+}
+
+fn find_up(pos: &Location, map: &[Vec<MapSquare>]) -> Location {
+    let new_row = if pos.row > 0 {
+        pos.row - 1
+    } else {
+        map.len() - 1
+    };
+    if let Some(square) = map[new_row].get(pos.column) {
+        if *square != MapSquare::Void {
+            return Location {
+                row: new_row,
+                column: pos.column,
+                direction: pos.direction,
+            };
+        }
+    }
+    let new_row = map
+        .iter()
+        .enumerate()
+        .rev()
+        .find(|(_, row)| !matches!(row.get(pos.column), Some(MapSquare::Void) | None))
+        .unwrap()
+        .0;
+    Location {
+        row: new_row,
+        column: pos.column,
+        direction: pos.direction,
+    }
+}
+
+fn draw_trail(
+    map: &[Vec<MapSquare>],
+    trail: &HashMap<(usize, usize), Direction>,
+    pos: Location,
+) -> String {
+    let width = map.iter().map(Vec::len).max().unwrap_or(0);
+    let max = Point2::new(width as i32 - 1, map.len() as i32 - 1);
+    let focus = Point2::new(pos.column as i32, pos.row as i32);
+    let (min, max) = visualize::window(Point2::ORIGIN, max, focus);
+    visualize::draw(min, max, |p| {
+        let (row, column) = (p.y as usize, p.x as usize);
+        match trail.get(&(row, column)) {
+            Some(Direction::Right) => '>',
+            Some(Direction::Down) => 'v',
+            Some(Direction::Left) => '<',
+            Some(Direction::Up) => '^',
+            None => match map[row].get(column) {
+                Some(MapSquare::Wall) => '#',
+                Some(MapSquare::Open) => '.',
+                Some(MapSquare::Void) | None => ' ',
+            },
+        }
+    })
+}
+
+fn mark(trail: &mut Option<HashMap<(usize, usize), Direction>>, pos: Location) {
+    if let Some(trail) = trail {
+        trail.insert((pos.row, pos.column), pos.direction);
+    }
+}
+
+// `next` gives the location one step ahead, wrapping around the edges of the map or the cube
+fn follow_path(notes: &Notes, next: impl Fn(Location) -> Location) -> Location {
+    let Notes { map, path } = notes;
+    let mut pos = find_start(map);
+    // the last facing on every square walked, only kept for drawing
+    let mut trail = visualize::enabled().then(HashMap::new);
+    mark(&mut trail, pos);
+    for step in path {
+        match step {
+            PathStep::TurnRight => pos.direction = pos.direction.turn_right(),
+            PathStep::TurnLeft => pos.direction = pos.direction.turn_left(),
+            PathStep::Forward(num) => {
+                for _ in 0..*num {
+                    let next = next(pos);
+                    if map[next.row][next.column] != MapSquare::Open {
+                        break;
+                    }
+                    pos = next;
+                    mark(&mut trail, pos);
+                }
+            }
+        }
+        mark(&mut trail, pos);
+        if let Some(trail) = &trail {
+            visualize::frame(
+                format_args!("Row {}, column {}", pos.row + 1, pos.column + 1),
+                || draw_trail(map, trail, pos),
+            );
+        }
+    }
+    pos
+}
+
+pub struct Notes {
+    map: Vec<Vec<MapSquare>>,
+    path: Vec<PathStep>,
+}
+
+pub fn parse(input: &str) -> Result<Notes, ParseError> {
+    let mut blocks = blocks(input);
+    let map = parse_map(blocks.next().expect("split always yields a block"))?;
+    let path = blocks
+        .next()
+        .and_then(|block| block.lines().next())
+        .ok_or_else(|| ParseError::end_of_input(input, "missing path"))?;
+    let path = parse_path(path)?;
+    Ok(Notes { map, path })
+}
+
+fn password(end: Location) -> usize {
+    1000 * (end.row + 1) + 4 * (end.column + 1) + facing(end.direction)
+}
+
+pub fn part1(notes: &Notes) -> usize {
+    let map = &notes.map;
+    password(follow_path(notes, |pos| match pos.direction {
+        Direction::Right => find_right(&pos, map),
+        Direction::Down => find_down(&pos, map),
+        Direction::Left => find_left(&pos, map),
+        Direction::Up => find_up(&pos, map),
+    }))
+}
+
+pub fn part2(notes: &Notes, cube: &Cube) -> Result<usize, CubeMismatch> {
+    if !cube.fits(&notes.map) {
+        return Err(CubeMismatch);
+    }
+    Ok(password(follow_path(notes, |pos| match pos.direction {
+        Direction::Right => cube.right(pos),
+        Direction::Down => cube.down(pos),
+        Direction::Left => cube.left(pos),
+        Direction::Up => cube.up(pos),
+    })))
+}
+
+pub fn input_cube() -> Cube {
+    Cube {
+        size: 50,
+        net: vec![
+            vec![
+                None,
+                Some([
+                    CubeEdge::Straight(0, 2),
+                    CubeEdge::Straight(1, 1),
+                    CubeEdge::Reverse(2, 0),
+                    CubeEdge::TurnRight(3, 0),
+                ]),
+                Some([
+                    CubeEdge::Reverse(2, 1),
+                    CubeEdge::TurnRight(1, 1),
+                    CubeEdge::Straight(0, 1),
+                    CubeEdge::Straight(3, 0),
+                ]),
+            ],
+            vec![
+                None,
+                Some([
+                    CubeEdge::TurnLeft(0, 2),
+                    CubeEdge::Straight(2, 1),
+                    CubeEdge::TurnLeft(2, 0),
+                    CubeEdge::Straight(0, 1),
+                ]),
+                None,
+            ],
+            vec![
+                Some([
+                    CubeEdge::Straight(2, 1),
+                    CubeEdge::Straight(3, 0),
+                    CubeEdge::Reverse(0, 1),
+                    CubeEdge::TurnRight(1, 1),
+                ]),
+                Some([
+                    CubeEdge::Reverse(0, 2),
+                    CubeEdge::TurnRight(3, 0),
+                    CubeEdge::Straight(2, 0),
+                    CubeEdge::Straight(1, 1),
+                ]),
+                None,
+            ],
+            vec![
+                Some([
+                    CubeEdge::TurnLeft(2, 1),
+                    CubeEdge::Straight(0, 2),
+                    CubeEdge::TurnLeft(0, 1),
+                    CubeEdge::Straight(2, 0),
+                ]),
+                None,
+                None,
+            ],
+        ],
+    }
+}
+
+// the folding of the net in the puzzle input
+impl Default for Cube {
+    fn default() -> Self {
+        input_cube()
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input<'a> = Notes;
+    type Config = Cube;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = CubeMismatch;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>, cube: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        part2(input, cube)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_cube() -> Cube {
+        Cube {
+            size: 4,
+            net: vec![
+                vec![
+                    None,
+                    None,
+                    Some([
+                        CubeEdge::Reverse(2, 3),
+                        CubeEdge::Straight(1, 2),
+                        CubeEdge::TurnLeft(1, 1),
+                        CubeEdge::Straight(2, 2),
+                    ]),
+                    None,
+                ],
+                vec![
+                    Some([
+                        CubeEdge::Straight(1, 1),
+                        CubeEdge::Reverse(2, 2),
+                        CubeEdge::TurnRight(2, 3),
+                        CubeEdge::Reverse(0, 2),
+                    ]),
+                    Some([
+                        CubeEdge::Straight(1, 2),
+                        CubeEdge::TurnLeft(2, 2),
+                        CubeEdge::Straight(1, 0),
+                        CubeEdge::TurnRight(0, 2),
+                    ]),
+                    Some([
+                        CubeEdge::TurnRight(2, 3),
+                        CubeEdge::Straight(2, 2),
+                        CubeEdge::Straight(1, 1),
+                        CubeEdge::Straight(0, 2),
+                    ]),
+                    None,
+                ],
+                vec![
+                    None,
+                    None,
+                    Some([
+                        CubeEdge::Straight(2, 3),
+                        CubeEdge::Reverse(1, 0),
+                        CubeEdge::TurnRight(1, 1),
+                        CubeEdge::Straight(1, 2),
+                    ]),
+                    Some([
+                        CubeEdge::Reverse(0, 2),
+                        CubeEdge::TurnRight(1, 0),
+                        CubeEdge::Straight(2, 2),
+                        CubeEdge::TurnLeft(1, 2),
+                    ]),
+                ],
+            ],
+        }
+    }
+
+    #[test]
+    fn example_part1() {
+        let notes = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(6032, part1(&notes));
+    }
+
+    #[test]
+    fn example_part2() {
+        let notes = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Ok(5031), part2(&notes, &example_cube()));
+    }
+
+    #[test]
+    fn other_cube() {
+        let notes = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Err(CubeMismatch), part2(&notes, &input_cube()));
+        let input = parse(include_str!("input.txt")).unwrap();
+        assert_eq!(Err(CubeMismatch), part2(&input, &example_cube()));
+    }
+}
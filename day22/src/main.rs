@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run::<day22::Day22>(include_str!("input.txt"));
+}
@@ -0,0 +1,197 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use aoc_common::{
+    render::{self, Image},
+    visualize, Direction, Grid, NoAnswer, ParseError, Point2, Solution,
+};
+
+pub fn parse(input: &str) -> Result<HashSet<Point2>, ParseError> {
+    let grid = Grid::parse(input, |_, ch| match ch {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    let elves = grid
+        .iter()
+        .filter(|(_, elf)| **elf)
+        .map(|(position, _)| position)
+        .collect::<HashSet<_>>();
+    if elves.is_empty() {
+        return Err(ParseError::end_of_input(input, "no elves"));
+    }
+    Ok(elves)
+}
+
+fn draw_elves(elves: &HashSet<Point2>) -> String {
+    let (min, max) = Point2::bounds(elves.iter().copied()).unwrap();
+    let centre = Point2::new((min.x + max.x) / 2, (min.y + max.y) / 2);
+    let (min, max) = visualize::window(min, max, centre);
+    visualize::draw(
+        min,
+        max,
+        |position| {
+            if elves.contains(&position) {
+                '#'
+            } else {
+                '.'
+            }
+        },
+    )
+}
+
+fn render_elves(elves: &HashSet<Point2>) -> Image {
+    let (min, max) = Point2::bounds(elves.iter().copied()).unwrap();
+    render::draw(min, max, |position| {
+        if elves.contains(&position) {
+            [90, 200, 90]
+        } else {
+            [20, 20, 30]
+        }
+    })
+}
+
+fn count_empty_spaces(elves: &HashSet<Point2>) -> usize {
+    let (min, max) = Point2::bounds(elves.iter().copied()).unwrap();
+    (min.x..=max.x)
+        .flat_map(|x| (min.y..=max.y).filter(move |y| !elves.contains(&Point2::new(x, *y))))
+        .count()
+}
+
+fn get_proposal(
+    elf: Point2,
+    elves: &HashSet<Point2>,
+    directions: &VecDeque<Direction>,
+) -> Option<Point2> {
+    let occupied = |x, y| elves.contains(&(elf + Point2::new(x, y)));
+    let nw = occupied(-1, -1);
+    let n = occupied(0, -1);
+    let ne = occupied(1, -1);
+    let w = occupied(-1, 0);
+    let e = occupied(1, 0);
+    let sw = occupied(-1, 1);
+    let s = occupied(0, 1);
+    let se = occupied(1, 1);
+    if nw || n || ne || w || e || sw || s || se {
+        for dir in directions {
+            let free = match *dir {
+                Direction::Up => !nw && !n && !ne,
+                Direction::Down => !sw && !s && !se,
+                Direction::Left => !nw && !w && !sw,
+                Direction::Right => !ne && !e && !se,
+            };
+            if free {
+                return Some(elf.step(*dir));
+            }
+        }
+    }
+    None
+}
+
+fn perform_round(elves: &HashSet<Point2>, directions: &VecDeque<Direction>) -> HashSet<Point2> {
+    let proposals = elves
+        .iter()
+        .map(|elf| (*elf, get_proposal(*elf, elves, directions)))
+        .collect::<Vec<_>>();
+    let mut counts: HashMap<&Point2, usize> = HashMap::new();
+    for (_, proposal) in proposals.iter() {
+        if let Some(pos) = proposal {
+            *counts.entry(pos).or_default() += 1;
+        }
+    }
+    proposals
+        .iter()
+        .map(|(elf, proposal)| match proposal {
+            Some(pos) => {
+                if counts.get(pos) == Some(&1) {
+                    *pos
+                } else {
+                    *elf
+                }
+            }
+            None => *elf,
+        })
+        .collect()
+}
+
+fn initial_directions() -> VecDeque<Direction> {
+    VecDeque::from([
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ])
+}
+
+pub fn part1(elves: &HashSet<Point2>) -> usize {
+    let mut elves = elves.clone();
+    let mut directions = initial_directions();
+    for round in 1..=10 {
+        elves = perform_round(&elves, &directions);
+        directions.rotate_left(1);
+        visualize::frame(format_args!("End of round {}", round), || {
+            draw_elves(&elves)
+        });
+        render::frame(|| render_elves(&elves));
+    }
+    render::last(|| render_elves(&elves));
+    count_empty_spaces(&elves)
+}
+
+// the first round in which no elf moves
+pub fn part2(elves: &HashSet<Point2>) -> Option<usize> {
+    let mut elves = elves.clone();
+    let mut directions = initial_directions();
+    for round in 1.. {
+        let next = perform_round(&elves, &directions);
+        if next == elves {
+            render::last(|| render_elves(&elves));
+            return Some(round);
+        }
+        elves = next;
+        directions.rotate_left(1);
+        visualize::frame(format_args!("End of round {}", round), || {
+            draw_elves(&elves)
+        });
+        render::frame(|| render_elves(&elves));
+    }
+    None
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = HashSet<Point2>;
+    type Config = ();
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = NoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        part2(input).ok_or(NoAnswer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let elves = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(110, part1(&elves));
+    }
+
+    #[test]
+    fn example_part2() {
+        let elves = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Some(20), part2(&elves));
+    }
+}
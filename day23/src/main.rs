@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run::<day23::Day23>(include_str!("input.txt"));
+}
@@ -0,0 +1,260 @@
+use std::{collections::HashSet, iter};
+
+use aoc_common::{
+    parse::{lines, ParseError},
+    render::{self, Image, Rgb},
+    visualize, Direction, Grid, NoAnswer, Point2, Solution,
+};
+
+#[derive(Clone, Copy)]
+struct Blizzard {
+    direction: Direction,
+    position: Point2,
+}
+
+impl Blizzard {
+    fn next(&self, width: i32, height: i32) -> Blizzard {
+        let mut position = self.position.step(self.direction);
+        if position.x < 1 {
+            position.x = width;
+        } else if position.x > width {
+            position.x = 1;
+        }
+        if position.y < 1 {
+            position.y = height;
+        } else if position.y > height {
+            position.y = 1;
+        }
+        Blizzard {
+            direction: self.direction,
+            position,
+        }
+    }
+}
+
+pub struct Valley {
+    height: i32,
+    width: i32,
+    entrance: Point2,
+    exit: Point2,
+    initial_blizzards: Vec<Blizzard>,
+}
+
+impl Valley {
+    fn parse(input: &str) -> Result<Valley, ParseError> {
+        let map = Grid::parse(input, |_, ch| {
+            matches!(ch, '#' | '.' | '^' | '>' | 'v' | '<').then_some(ch)
+        })?;
+        if map.width() < 3 || map.height() < 3 {
+            return Err(ParseError::end_of_input(input, "the valley is too small"));
+        }
+        let height = map.height() as i32 - 2;
+        let width = map.width() as i32 - 2;
+        let gap = |y: i32| (0..=width + 1).find(|x| map[Point2::new(*x, y)] == '.');
+        let walls = lines(input).collect::<Vec<_>>();
+        let (top, bottom) = (walls[0], walls[walls.len() - 1]);
+        let entrance_x = gap(0).ok_or_else(|| top.error("no entrance in the top wall"))?;
+        let exit_x = gap(height + 1).ok_or_else(|| bottom.error("no exit in the bottom wall"))?;
+        Ok(Valley {
+            height,
+            width,
+            entrance: Point2::new(entrance_x, 0),
+            exit: Point2::new(exit_x, height + 1),
+            initial_blizzards: map
+                .iter()
+                .filter_map(|(position, ch)| {
+                    Direction::from_arrow(*ch).map(|direction| Blizzard {
+                        direction,
+                        position,
+                    })
+                })
+                .collect(),
+        })
+    }
+
+    fn update_blizzards(&self, blizzards: &[Blizzard]) -> Vec<Blizzard> {
+        blizzards
+            .iter()
+            .map(|blizzard| blizzard.next(self.width, self.height))
+            .collect()
+    }
+
+    fn is_wall(&self, pos: Point2) -> bool {
+        let outside = pos.x == 0 || pos.y == 0 || pos.x > self.width || pos.y > self.height;
+        outside && pos != self.entrance && pos != self.exit
+    }
+
+    // the expedition's possible positions as E, and a blizzard's direction or the
+    // number of them sharing a position
+    fn draw(
+        &self,
+        blizzards: &[Blizzard],
+        expedition: &HashSet<Point2>,
+        min: Point2,
+        max: Point2,
+    ) -> String {
+        let grid = self.build_occupation_grid(blizzards);
+        visualize::draw(min, max, |pos| {
+            if self.is_wall(pos) {
+                '#'
+            } else if expedition.contains(&pos) {
+                'E'
+            } else {
+                match grid[pos] {
+                    0 => '.',
+                    1 => blizzards
+                        .iter()
+                        .find(|b| b.position == pos)
+                        .map_or('?', |b| b.direction.arrow()),
+                    n => char::from_digit(n as u32, 10).unwrap_or('*'),
+                }
+            }
+        })
+    }
+
+    fn build_occupation_grid(&self, blizzards: &[Blizzard]) -> Grid<usize> {
+        let width = self.width as usize + 2;
+        let height = self.height as usize + 2;
+        let mut grid = Grid::from_fn(width, height, |pos| usize::from(self.is_wall(pos)));
+        for b in blizzards {
+            grid[b.position] += 1;
+        }
+        grid
+    }
+}
+
+fn get_next_steps(pos: &Point2, grid: &Grid<usize>) -> Vec<Point2> {
+    iter::once(*pos)
+        .chain(pos.neighbours4())
+        .filter(|next| grid.get(*next) == Some(&0))
+        .collect()
+}
+
+fn colour(tile: char) -> Rgb {
+    match tile {
+        '#' => [110, 110, 110],
+        'E' => [255, 80, 80],
+        '.' => [20, 20, 30],
+        '^' | '>' | 'v' | '<' => [120, 170, 255],
+        _ => [200, 230, 255],
+    }
+}
+
+// None once the blizzards are back where they were with the expedition no further
+// along, as nothing new can happen after that
+fn get_steps(
+    start: Point2,
+    end: Point2,
+    valley: &Valley,
+    blizzards: &mut Vec<Blizzard>,
+) -> Option<usize> {
+    let mut possible_positions = HashSet::new();
+    possible_positions.insert(start);
+    let period = lcm(valley.width as usize, valley.height as usize);
+    let mut seen = HashSet::new();
+    for steps in 1.. {
+        *blizzards = valley.update_blizzards(blizzards);
+        let grid = valley.build_occupation_grid(blizzards);
+        possible_positions = possible_positions
+            .iter()
+            .flat_map(|pos| get_next_steps(pos, &grid))
+            .collect();
+        let max = Point2::new(valley.width + 1, valley.height + 1);
+        visualize::frame(format_args!("Minute {}", steps), || {
+            let (min, max) = visualize::window(Point2::ORIGIN, max, end);
+            valley.draw(blizzards, &possible_positions, min, max)
+        });
+        render::frame(|| {
+            let text = valley.draw(blizzards, &possible_positions, Point2::ORIGIN, max);
+            Image::from_text(&text, colour)
+        });
+        if possible_positions.contains(&end) {
+            render::last(|| {
+                let text = valley.draw(blizzards, &possible_positions, Point2::ORIGIN, max);
+                Image::from_text(&text, colour)
+            });
+            return Some(steps);
+        }
+        let mut positions = possible_positions.iter().copied().collect::<Vec<_>>();
+        positions.sort_unstable_by_key(|pos| (pos.y, pos.x));
+        if !seen.insert((steps % period, positions)) {
+            return None;
+        }
+    }
+    unreachable!()
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    let gcd = |mut a: usize, mut b: usize| {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    };
+    a / gcd(a, b) * b
+}
+
+pub fn parse(input: &str) -> Result<Valley, ParseError> {
+    Valley::parse(input)
+}
+
+pub fn part1(valley: &Valley) -> Option<usize> {
+    let mut blizzards = valley.initial_blizzards.clone();
+    get_steps(valley.entrance, valley.exit, valley, &mut blizzards)
+}
+
+// there, back for the snacks, and there again
+pub fn part2(valley: &Valley) -> Option<usize> {
+    let mut blizzards = valley.initial_blizzards.clone();
+    Some(
+        get_steps(valley.entrance, valley.exit, valley, &mut blizzards)?
+            + get_steps(valley.exit, valley.entrance, valley, &mut blizzards)?
+            + get_steps(valley.entrance, valley.exit, valley, &mut blizzards)?,
+    )
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input<'a> = Valley;
+    type Config = ();
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = NoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        part1(input).ok_or(NoAnswer)
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        part2(input).ok_or(NoAnswer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let valley = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Some(18), part1(&valley));
+    }
+
+    #[test]
+    fn example_part2() {
+        let valley = parse(include_str!("example.txt")).unwrap();
+        assert_eq!(Some(54), part2(&valley));
+    }
+
+    #[test]
+    fn blocked_valley() {
+        let valley = parse("#.###\n#...#\n#>>>#\n#...#\n###.#\n").unwrap();
+        assert_eq!(None, part1(&valley));
+        assert_eq!(None, part2(&valley));
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run::<day24::Day24>(include_str!("input.txt"));
+}
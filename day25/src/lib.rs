@@ -0,0 +1,248 @@
+use std::{
+    error::Error,
+    fmt::{Display, Write},
+    iter::Sum,
+    str::FromStr,
+};
+
+use aoc_common::{
+    parse::{lines, ParseError},
+    NoAnswer, Solution,
+};
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct BalancedQuinary(i64);
+
+impl From<i64> for BalancedQuinary {
+    fn from(value: i64) -> Self {
+        BalancedQuinary(value)
+    }
+}
+
+impl Sum for BalancedQuinary {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(0, |a, b| a + b.0).into()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseBalancedQuinaryError {
+    UnknownDigit(char),
+}
+
+impl Display for ParseBalancedQuinaryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownDigit(ch) => write!(f, "Unknown digit: {}", ch),
+        }
+    }
+}
+
+impl Error for ParseBalancedQuinaryError {}
+
+impl FromStr for BalancedQuinary {
+    type Err = ParseBalancedQuinaryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.chars()
+            .try_fold(0, |mut acc, ch| {
+                acc *= 5;
+                match ch {
+                    '2' => Ok(acc + 2),
+                    '1' => Ok(acc + 1),
+                    '0' => Ok(acc),
+                    '-' => Ok(acc - 1),
+                    '=' => Ok(acc - 2),
+                    _ => Err(ParseBalancedQuinaryError::UnknownDigit(ch)),
+                }
+            })
+            .map(BalancedQuinary::from)
+    }
+}
+
+impl Display for BalancedQuinary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut digits = vec![];
+        let mut remaining = self.0;
+
+        while remaining != 0 {
+            match remaining % 5 {
+                0 => digits.push('0'),
+                1 => digits.push('1'),
+                2 => digits.push('2'),
+                3 => {
+                    digits.push('=');
+                    remaining += 5;
+                }
+                4 => {
+                    digits.push('-');
+                    remaining += 5;
+                }
+                -1 => digits.push('-'),
+                -2 => digits.push('='),
+                -3 => {
+                    digits.push('2');
+                    remaining -= 5;
+                }
+                -4 => {
+                    digits.push('1');
+                    remaining -= 5;
+                }
+                _ => panic!("Unexpected remainder: {}", remaining),
+            }
+            remaining /= 5;
+        }
+
+        if digits.is_empty() {
+            f.write_char('0')
+        } else {
+            digits.into_iter().rev().try_for_each(|c| f.write_char(c))
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<BalancedQuinary>, ParseError> {
+    lines(input).map(|line| line.parse()).collect()
+}
+
+pub fn part1(numbers: &[BalancedQuinary]) -> BalancedQuinary {
+    numbers.iter().copied().sum()
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input<'a> = Vec<BalancedQuinary>;
+    type Config = ();
+    type Part1 = BalancedQuinary;
+    type Part2 = String;
+    type Error = NoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part1, Self::Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(_input: &Self::Input<'_>, _config: &Self::Config) -> Result<Self::Part2, Self::Error> {
+        // day 25 has no second puzzle, its star comes with all the others
+        Err(NoAnswer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() {
+        let numbers = parse(include_str!("example.txt")).unwrap();
+        assert_eq!("2=-1=0", part1(&numbers).to_string());
+    }
+
+    #[test]
+    fn parse_balanced_quinary() {
+        assert_eq!(BalancedQuinary::from_str("0"), Ok(BalancedQuinary::from(0)));
+        assert_eq!(BalancedQuinary::from_str("1"), Ok(BalancedQuinary::from(1)));
+        assert_eq!(BalancedQuinary::from_str("2"), Ok(BalancedQuinary::from(2)));
+        assert_eq!(
+            BalancedQuinary::from_str("1="),
+            Ok(BalancedQuinary::from(3))
+        );
+        assert_eq!(
+            BalancedQuinary::from_str("1-"),
+            Ok(BalancedQuinary::from(4))
+        );
+        assert_eq!(
+            BalancedQuinary::from_str("10"),
+            Ok(BalancedQuinary::from(5))
+        );
+        assert_eq!(
+            BalancedQuinary::from_str("11"),
+            Ok(BalancedQuinary::from(6))
+        );
+        assert_eq!(
+            BalancedQuinary::from_str("12"),
+            Ok(BalancedQuinary::from(7))
+        );
+        assert_eq!(
+            BalancedQuinary::from_str("2="),
+            Ok(BalancedQuinary::from(8))
+        );
+        assert_eq!(
+            BalancedQuinary::from_str("2-"),
+            Ok(BalancedQuinary::from(9))
+        );
+        assert_eq!(
+            BalancedQuinary::from_str("20"),
+            Ok(BalancedQuinary::from(10))
+        );
+        assert_eq!(
+            BalancedQuinary::from_str("1=0"),
+            Ok(BalancedQuinary::from(15))
+        );
+        assert_eq!(
+            BalancedQuinary::from_str("1-0"),
+            Ok(BalancedQuinary::from(20))
+        );
+        assert_eq!(
+            BalancedQuinary::from_str("1=11-2"),
+            Ok(BalancedQuinary::from(2022))
+        );
+        assert_eq!(
+            BalancedQuinary::from_str("1-0---0"),
+            Ok(BalancedQuinary::from(12345))
+        );
+        assert_eq!(
+            BalancedQuinary::from_str("1121-1110-1=0"),
+            Ok(BalancedQuinary::from(314159265))
+        );
+        assert_eq!(
+            BalancedQuinary::from_str("--=-1---01-20"),
+            Ok(BalancedQuinary::from(-314159265))
+        );
+    }
+
+    #[test]
+    fn format_balanced_quinary() {
+        assert_eq!(BalancedQuinary::from(0).to_string(), "0");
+        assert_eq!(BalancedQuinary::from(1).to_string(), "1");
+        assert_eq!(BalancedQuinary::from(2).to_string(), "2");
+        assert_eq!(BalancedQuinary::from(3).to_string(), "1=");
+        assert_eq!(BalancedQuinary::from(4).to_string(), "1-");
+        assert_eq!(BalancedQuinary::from(5).to_string(), "10");
+        assert_eq!(BalancedQuinary::from(6).to_string(), "11");
+        assert_eq!(BalancedQuinary::from(7).to_string(), "12");
+        assert_eq!(BalancedQuinary::from(8).to_string(), "2=");
+        assert_eq!(BalancedQuinary::from(9).to_string(), "2-");
+        assert_eq!(BalancedQuinary::from(10).to_string(), "20");
+        assert_eq!(BalancedQuinary::from(15).to_string(), "1=0");
+        assert_eq!(BalancedQuinary::from(20).to_string(), "1-0");
+        assert_eq!(BalancedQuinary::from(2022).to_string(), "1=11-2");
+        assert_eq!(BalancedQuinary::from(12345).to_string(), "1-0---0");
+        assert_eq!(
+            BalancedQuinary::from(314159265).to_string(),
+            "1121-1110-1=0"
+        );
+        assert_eq!(BalancedQuinary::from(-1).to_string(), "-");
+        assert_eq!(BalancedQuinary::from(-2).to_string(), "=");
+        assert_eq!(BalancedQuinary::from(-3).to_string(), "-2");
+        assert_eq!(BalancedQuinary::from(-4).to_string(), "-1");
+        assert_eq!(BalancedQuinary::from(-5).to_string(), "-0");
+        assert_eq!(BalancedQuinary::from(-6).to_string(), "--");
+        assert_eq!(BalancedQuinary::from(-7).to_string(), "-=");
+        assert_eq!(BalancedQuinary::from(-8).to_string(), "=2");
+        assert_eq!(BalancedQuinary::from(-9).to_string(), "=1");
+        assert_eq!(BalancedQuinary::from(-10).to_string(), "=0");
+        assert_eq!(BalancedQuinary::from(-15).to_string(), "-20");
+        assert_eq!(BalancedQuinary::from(-20).to_string(), "-10");
+        assert_eq!(BalancedQuinary::from(-2022).to_string(), "-2--1=");
+        assert_eq!(BalancedQuinary::from(-12345).to_string(), "-101110");
+        assert_eq!(
+            BalancedQuinary::from(-314159265).to_string(),
+            "--=-1---01-20"
+        );
+    }
+}